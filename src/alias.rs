@@ -0,0 +1,222 @@
+/* 
+Copyright (c) 2024  NickelAnge.Studio 
+Email               mathieu.grenier@nickelange.studio
+Git                 https://github.com/NickelAngeStudio/nswnd
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+use proc_macro::{TokenStream, TokenTree, Delimiter, Group, Ident, Punct, Spacing, Span};
+
+use crate::{errors::{NSCFGError, NSCFGSpanError}, config::{find_nscfg_alias, set_source_aliases, get_nscfg_crate_path}, syntax::tokens_span};
+
+/// Prefix of the macro_rules! helper generated for each alias.
+pub(crate) const ALIAS_HELPER_PREFIX : &str = "nscfg_alias_";
+
+/// Inner attribute used by helpers to give alias definitions to a macro.
+const ALIAS_ATTRIBUTE : &str = "nscfg_alias";
+
+/// Option of cfg_alias! giving the path of nscfg crate when re-exported (`#![crate = path]`).
+const CRATE_PATH_OPTION : &str = "crate";
+
+/// Separator between alias definitions.
+const DEFINITION_SEPARATOR : char = ';';
+
+/// Separator between parameters of a parametrized alias.
+const PARAMETER_SEPARATOR : char = ',';
+
+/// Separator between alias name and expression.
+const DEFINITION_ASSIGN : char = '=';
+
+/// Generate a macro_rules! helper for each alias defined with cfg_alias!.
+/// 
+/// A macro that can't find an alias calls the helper with its own name and tokens. The helper calls
+/// the macro back with the alias definition added as `#![nscfg_alias(name = (expression))]`.
+/// 
+/// Error(s)
+/// Returns Err(Vec<[NSCFGSpanError]>) for each definition that isn't `name = expression` or `name(param, ...) = expression`
+/// or that is already defined, or if option isn't `#![crate = path]`.
+pub(crate) fn generate_alias_helpers(stream : TokenStream) -> Result<TokenStream, Vec<NSCFGSpanError>> {
+
+    let tokens : Vec<TokenTree> = stream.into_iter().collect();
+    let mut helpers = TokenStream::new();
+    let mut errors : Vec<NSCFGSpanError> = Vec::new();
+
+    // 1. Only aliases defined outside source can conflict.
+    set_source_aliases(Vec::new());
+
+    // 1.1. Path used by helpers to call macros back
+    let (path, position) = match tokens.as_slice() {
+        [TokenTree::Punct(hash), TokenTree::Punct(bang), TokenTree::Group(attribute), ..] if hash.as_char() == '#' && bang.as_char() == '!' && attribute.delimiter() == Delimiter::Bracket => {
+            match parse_crate_path(attribute) {
+                Ok(path) => (path, 3),
+                Err(err) => return Err(vec![err]),
+            }
+        },
+        _ => (get_nscfg_crate_path(), 0),
+    };
+
+    // 2. Generate helper of each definition
+    for definition in tokens[position..].split(|t| matches!(t, TokenTree::Punct(punct) if punct.as_char() == DEFINITION_SEPARATOR)) {
+        if definition.is_empty() {  // Last separator is optional
+            continue;
+        }
+
+        match parse_definition(definition) {
+            Ok((name, params, expression)) => helpers.extend(generate_helper(&name, &params, &expression, &path)),
+            Err(err) => errors.push(err),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(helpers)
+    } else {
+        Err(errors)
+    }
+
+}
+
+/// Extract alias definitions given by helpers at the beginning of stream and return the remaining tokens.
+/// 
+/// Definitions are kept for the macro being expanded.
+pub(crate) fn extract_source_aliases(stream : TokenStream) -> TokenStream {
+
+    let tokens : Vec<TokenTree> = stream.into_iter().collect();
+    let mut aliases : Vec<(String, String)> = Vec::new();
+    let mut position = 0;
+
+    // 1. Read each #![nscfg_alias(name = (expression))] or #![nscfg_alias(name(params) = (expression))]
+    while let [TokenTree::Punct(hash), TokenTree::Punct(bang), TokenTree::Group(attribute), ..] = &tokens[position..] {
+        if hash.as_char() != '#' || bang.as_char() != '!' || attribute.delimiter() != Delimiter::Bracket {
+            break;
+        }
+
+        let attribute : Vec<TokenTree> = attribute.stream().into_iter().collect();
+        match attribute.as_slice() {
+            [TokenTree::Ident(ident), TokenTree::Group(definition)] if ident.to_string().eq(ALIAS_ATTRIBUTE) => {
+                match definition.stream().into_iter().collect::<Vec<TokenTree>>().as_slice() {
+                    [TokenTree::Ident(name), TokenTree::Punct(_), TokenTree::Group(expression)] => aliases.push((name.to_string(), expression.stream().to_string())),
+                    [TokenTree::Ident(name), TokenTree::Group(params), TokenTree::Punct(_), TokenTree::Group(expression)] => aliases.push((format!("{}{}", name, params), expression.stream().to_string())),
+                    _ => break,
+                }
+            },
+            _ => break,
+        }
+
+        position += 3;
+    }
+
+    // 2. Keep aliases for macro expansion
+    set_source_aliases(aliases);
+
+    tokens[position..].iter().cloned().collect()
+
+}
+
+/// Call helper of the first alias not found so a cfg_alias! defining it can give its definition back.
+/// 
+/// A missing helper is reported by rustc on the alias. Returns None if no alias not found can name a helper, so errors are reported as they are.
+pub(crate) fn defer_to_alias_helper(macro_name : &str, stream : TokenStream, errors : &[NSCFGSpanError]) -> Option<TokenStream> {
+
+    // 1. Find first alias without match
+    let (alias, span) = errors.iter().find_map(|err| match &err.error {
+        NSCFGError::AliasNotFound(alias, _) if is_identifier(alias) => Some((alias, err.start)),
+        _ => None,
+    })?;
+
+    // 2. Generate `nscfg_alias_name!{ macro_name { stream } }`. Helper is located on alias so a missing helper is reported there.
+    let mut callback = TokenStream::from(TokenTree::from(Ident::new(macro_name, Span::call_site())));
+    callback.extend(TokenStream::from(TokenTree::from(Group::new(Delimiter::Brace, stream))));
+
+    let mut punct = Punct::new('!', Spacing::Alone);
+    punct.set_span(span);
+
+    Some(TokenStream::from_iter([
+        TokenTree::from(Ident::new(&format!("{}{}", ALIAS_HELPER_PREFIX, alias), span)),
+        TokenTree::from(punct),
+        TokenTree::from(Group::new(Delimiter::Brace, callback)),
+    ]))
+
+}
+
+/// Parse a `name = expression` or `name(param, ...) = expression` definition.
+/// 
+/// Error(s)
+/// Returns Err([NSCFGSpanError]) if definition syntax is incorrect or if alias is already defined.
+fn parse_definition(definition : &[TokenTree]) -> Result<(Ident, TokenStream, TokenStream), NSCFGSpanError> {
+
+    let content = definition.iter().cloned().collect::<TokenStream>().to_string();
+    let (start, end) = tokens_span(definition, Span::call_site());
+
+    // 1. Split name, parameters and expression
+    let (name, params, assign, expression) = match definition {
+        [TokenTree::Ident(name), TokenTree::Punct(assign), expression @ ..] => (name, TokenStream::new(), assign, expression),
+        [TokenTree::Ident(name), TokenTree::Group(params), TokenTree::Punct(assign), expression @ ..] if is_parameter_list(params) => (name, TokenStream::from(TokenTree::from(params.clone())), assign, expression),
+        _ => return Err(NSCFGError::AliasDefinitionError.between(&content, start, end)),
+    };
+
+    if assign.as_char() != DEFINITION_ASSIGN || assign.spacing() != Spacing::Alone || expression.is_empty() {
+        return Err(NSCFGError::AliasDefinitionError.between(&content, start, end));
+    }
+
+    // 2. Alias must not be defined elsewhere
+    match find_nscfg_alias(&name.to_string()) {
+        Ok(Some((_, _, source))) => Err(NSCFGError::AliasAlreadyDefined(name.to_string(), source).at(&content, name.span())),
+        Ok(None) => Ok((name.clone(), params, expression.iter().cloned().collect())),
+        Err(err) => Err(err.between(&content, start, end)),
+    }
+
+}
+
+/// Returns true if group is a parenthesized list of parameters like `(arch, feat)`.
+fn is_parameter_list(group : &Group) -> bool {
+    let tokens : Vec<TokenTree> = group.stream().into_iter().collect();
+
+    group.delimiter() == Delimiter::Parenthesis && !tokens.is_empty() && tokens.split(|t| matches!(t, TokenTree::Punct(punct) if punct.as_char() == PARAMETER_SEPARATOR))
+        .all(|param| matches!(param, [TokenTree::Ident(_)]))
+}
+
+/// Parse the `crate = path` option giving the path of nscfg crate when it is re-exported.
+/// 
+/// Error(s)
+/// Returns Err([NSCFGSpanError]) if attribute isn't `crate = path`.
+fn parse_crate_path(attribute : &Group) -> Result<String, NSCFGSpanError> {
+
+    let tokens : Vec<TokenTree> = attribute.stream().into_iter().collect();
+
+    match tokens.as_slice() {
+        [TokenTree::Ident(option), TokenTree::Punct(assign), path @ ..] if option.to_string().eq(CRATE_PATH_OPTION) && assign.as_char() == DEFINITION_ASSIGN && !path.is_empty() => {
+            Ok(path.iter().cloned().collect::<TokenStream>().to_string())
+        },
+        _ => Err(NSCFGError::AliasOptionError.at(&attribute.stream().to_string(), attribute.span())),
+    }
+
+}
+
+/// Generate the macro_rules! helper of an alias calling macros back from nscfg crate path.
+fn generate_helper(name : &Ident, params : &TokenStream, expression : &TokenStream, path : &str) -> TokenStream {
+    format!("#[allow(unused_macros)] macro_rules! {prefix}{name} {{ ($macro:ident {{ $($tokens:tt)* }}) => {{ {path}::$macro!{{ #![{attribute}({name}{params} = ({expression}))] $($tokens)* }} }}; }}",
+        prefix = ALIAS_HELPER_PREFIX, name = name, params = params, path = path, attribute = ALIAS_ATTRIBUTE, expression = expression).parse().unwrap()
+}
+
+/// Returns true if alias can be used in a helper name.
+#[inline(always)]
+fn is_identifier(alias : &str) -> bool {
+    alias.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') && alias.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+}
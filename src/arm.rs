@@ -22,9 +22,9 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
 
-use proc_macro::{TokenStream, TokenTree, Delimiter};
+use proc_macro::{TokenStream, TokenTree, Delimiter, Span, Spacing, Punct, Group};
 
-use crate::{errors::NSCFGError, config::{DOC_ALIAS, is_nscfg_autodoc, if_docsrs_enabled}, syntax::{SyntaxTreeNode, AND_SYMBOL, OR_SYMBOL, NEGATIVE_SYMBOL}, NscfgMacroSource};
+use crate::{errors::{NSCFGError, NSCFGSpanError}, config::{DOC_ALIAS, is_nscfg_autodoc, if_docsrs_enabled, get_supported_targets, get_tracked_files}, syntax::{SyntaxTreeNode, Node, AND_SYMBOL, OR_SYMBOL, NEGATIVE_SYMBOL}, target::{TargetCfg, split_configurations}, options::TargetOptions, item, NscfgMacroSource};
 
 #[allow(unused_imports)]
 use crate::config::{get_release_modifier_behaviour, ReleaseModifierBehaviour};
@@ -54,6 +54,18 @@ pub(crate) const MODIFIER_DEACTIVATE_VALUE: &str = "any()";   // Is always false
 /// Panic arm modifier used to see arm parameters
 pub(crate) const MODIFIER_PANIC: char = '@'; 
 
+/// Maximum number of configurations split to name the one not covered by exhaustive target_cfg!
+const MAX_NAMED_CONFIGURATIONS : usize = 256;
+
+/// Closure parameters delimiter
+const CLOSURE_DELIMITER : char = '|';
+
+/// Keyword before a type
+const TYPE_CAST_KEYWORD : &str = "as";
+
+/// Keyword before closure parameters
+const CLOSURE_MOVE_KEYWORD : &str = "move";
+
 
 
 /// Enumeration of possible arm types
@@ -85,6 +97,91 @@ pub(crate) enum TargetArmModifier {
     Panic,
 }
 
+/// Context of arm content used to find the `,` that ends an arm, like the match arm parser of rustc.
+/// 
+/// Commas inside generics `HashMap::<K, V>` and closure parameters `|a, b|` don't end the arm.
+#[derive(Default)]
+struct ContentContext {
+    angle_depth : usize,            // Depth of generic angle brackets
+    closure_params : bool,          // True inside closure parameters
+    type_context : bool,            // True after `->`, `as` or `:` where `<` opens generics
+    type_only : bool,               // True if content is a type, where `<` always opens generics
+    previous : Option<TokenTree>,   // Previous token of content
+}
+
+impl ContentContext {
+    /// Create context for the content of a new arm.
+    fn new(type_only : bool) -> ContentContext {
+        ContentContext { type_only, ..Default::default() }
+    }
+
+    /// Returns true if token is the `,` ending the arm.
+    fn is_arm_end(&self, token : &TokenTree) -> bool {
+        matches!(token, TokenTree::Punct(punct) if punct.as_char() == ARM_SEPARATOR) && self.angle_depth == 0 && !self.closure_params
+    }
+
+    /// Update context with the next token of content.
+    fn update(&mut self, token : &TokenTree) {
+        match token {
+            TokenTree::Punct(punct) => match punct.as_char() {
+                '<' => {
+                    // Generics follow a path separator `::<`, a type or start a qualified path `<T as Trait>::`.
+                    if self.angle_depth > 0 || self.type_context || self.type_only || self.previous.is_none() || self.is_previous(':', None) {
+                        self.angle_depth += 1;
+                    }
+                },
+                '>' => {
+                    if self.is_previous('-', Some(Spacing::Joint)) {  // `->` return type
+                        self.type_context = true;
+                    } else if self.angle_depth > 0 {
+                        self.angle_depth -= 1;
+                    }
+                },
+                ':' => {
+                    // Single `:` is followed by a type. `::` is a path separator.
+                    if punct.spacing() == Spacing::Alone && !self.is_previous(':', Some(Spacing::Joint)) {
+                        self.type_context = true;
+                    }
+                },
+                CLOSURE_DELIMITER if self.angle_depth == 0 => {
+                    if self.closure_params {    // End of closure parameters
+                        self.closure_params = false;
+                        self.type_context = false;
+                    } else if !self.is_previous(CLOSURE_DELIMITER, Some(Spacing::Joint)) && self.is_expression_start() {
+                        self.closure_params = true;
+                    }
+                },
+                '&' | '\'' | '-' => {},  // References, lifetimes and `->` can be part of a type
+                _ => if self.angle_depth == 0 {
+                    self.type_context = false;
+                },
+            },
+            TokenTree::Ident(ident) if ident.to_string().eq(TYPE_CAST_KEYWORD) => self.type_context = true,
+            TokenTree::Group(grp) if grp.delimiter() == Delimiter::Brace && self.angle_depth == 0 => self.type_context = false,   // Closure or block body
+            _ => {},
+        }
+
+        self.previous = Some(token.clone());
+    }
+
+    /// Returns true if previous token is punct with character and spacing (any spacing if None).
+    #[inline(always)]
+    fn is_previous(&self, c : char, spacing : Option<Spacing>) -> bool {
+        matches!(&self.previous, Some(TokenTree::Punct(punct)) if punct.as_char() == c && spacing.is_none_or(|spacing| punct.spacing() == spacing))
+    }
+
+    /// Returns true if an expression can start after previous token, which means `|` opens closure parameters instead of being a `|` operator.
+    #[inline(always)]
+    fn is_expression_start(&self) -> bool {
+        match &self.previous {
+            None => true,
+            Some(TokenTree::Punct(_)) => true,
+            Some(TokenTree::Ident(ident)) => ident.to_string().eq(CLOSURE_MOVE_KEYWORD),
+            _ => false,
+        }
+    }
+}
+
 /// Struct used that contains an arm type, it's attributes and content.
 #[derive(Clone)]
 pub(crate) struct TargetArm {
@@ -94,7 +191,10 @@ pub(crate) struct TargetArm {
     pub pred_ts : TokenStream,      // Predicates tokenstream
     pub cfg_ts : TokenStream,       // Tokenstream for #[cfg]
     pub attr_ts : TokenStream,      // Tokenstream for #[cfg_attr]
+    pub warning_ts : TokenStream,   // Tokenstream for warnings
     pub content : TokenStream,      // Right side tokenstream
+    pub span : Option<Span>,        // Span of the first token of arm
+    pub valid : bool,               // False if an error occurred while extracting arm
 }
 
 impl ToString for TargetArm {
@@ -108,18 +208,46 @@ impl ToString for TargetArm {
 impl TargetArm {
     /// Create a new empty normal arm.
     pub fn new() -> TargetArm {
-        TargetArm { arm_type : TargetArmType::Simplified, modifier:TargetArmModifier::None, arm_ts : TokenStream::new(), pred_ts : TokenStream::new(), cfg_ts : TokenStream::new(), attr_ts : TokenStream::new(), content : TokenStream::new() }
+        TargetArm { arm_type : TargetArmType::Simplified, modifier:TargetArmModifier::None, arm_ts : TokenStream::new(), pred_ts : TokenStream::new(), cfg_ts : TokenStream::new(), attr_ts : TokenStream::new(), warning_ts : TokenStream::new(), content : TokenStream::new(), span : None, valid : true }
+    }
+
+    /// Get the span of the arm first token. Default to macro call site.
+    pub fn span(&self) -> Span {
+        self.span.unwrap_or_else(Span::call_site)
+    }
+
+    /// Get the arm as written, used in messages.
+    pub fn name(&self) -> String {
+        match self.arm_type {
+            TargetArmType::Simplified => Self::compact_ts(self.arm_ts.clone()),
+            TargetArmType::Legacy => self.arm_ts.to_string(),
+            TargetArmType::Wildcard => String::from(WILDCARD_ARM_STR),
+        }
+    }
+
+    /// Get the predicate written inside cfg_ts `#[cfg(...)]`.
+    pub fn cfg_predicate(&self) -> TokenStream {
+        match self.cfg_ts.clone().into_iter().nth(1) {
+            Some(TokenTree::Group(attr)) => match attr.stream().into_iter().nth(1) {
+                Some(TokenTree::Group(pred)) => pred.stream(),
+                _ => TokenStream::new(),
+            },
+            _ => TokenStream::new(),
+        }
     }
 
     /// Extract target arms into a vector from macro source.
     /// 
-    /// Panic
-    /// Will panic if no Wildcard arm inserted.
-    pub fn extract(source : TokenStream, macro_src : NscfgMacroSource) -> Vec<TargetArm> {
+    /// Error(s)
+    /// Returns Err(Vec<[NSCFGSpanError]>) with every error found, each located on the faulty tokens.
+    pub fn extract(source : TokenStream, macro_src : NscfgMacroSource) -> Result<Vec<TargetArm>, Vec<NSCFGSpanError>> {
 
         // Vector of all arms
         let mut arms : Vec<TargetArm> = Vec::new();
 
+        // Vector of all errors found
+        let mut errors : Vec<NSCFGSpanError> = Vec::new();
+
         // Arm used to extract attr and content.
         let mut arm = TargetArm::new();
 
@@ -129,35 +257,171 @@ impl TargetArm {
         // Flag for 1st part of separator
         let mut separator  = false;
 
+        // Context of content to find arm end
+        let mut context = ContentContext::new(matches!(macro_src, NscfgMacroSource::TypeMacro));
+
         // 1. Extract Tokens from source
         for token in source {
+            // Keep span of arm first token
+            if left_side && arm.span.is_none() {
+                arm.span = Some(token.span());
+            }
+
             // Handle punct to see if left or right side
-            if !Self::handle_arm_separator(&mut arm, token.clone(), &mut left_side, &mut separator) {
-                // If token was not handled by separator
+            let handled = match Self::handle_arm_separator(&mut arm, token.clone(), &mut left_side, &mut separator) {
+                Ok(handled) => handled,
+                Err(err) => {
+                    Self::add_error(&mut errors, &mut arm, err);
+                    true
+                },
+            };
+
+            // If token was not handled by separator
+            if !handled {
                 if left_side {  // Extract for left side (attributes)
-                    Self::extract_attributes(&mut arm, token);
+                    if let Err(err) = Self::extract_attributes(&mut arm, token) {
+                        // An arm separator on left side means the arm has no content. Continue with next arm.
+                        let skip_arm = matches!(err.error, NSCFGError::ContentSeparatorMissing);
+                        Self::add_error(&mut errors, &mut arm, err);
+                        if skip_arm {
+                            arm = TargetArm::new();
+                        }
+                    }
                 } else {    // Extract for right side (content)
-                    Self::extract_content(&mut arm, &mut arms, token, &mut left_side);
+                    Self::extract_content(&mut arm, &mut arms, token, &mut left_side, &mut context);
                 }
             }
         }
 
-        // 2. Add last arm if it were not added (missing `,` at last entry is not an error.)
-        if !left_side {
+        // 2. Add last arm if it were not added (missing `,` at last entry is not an error.) Invalid arm is kept for integrity verification.
+        if !left_side || !arm.valid {
             Self::add_arm(&mut arms, &mut arm, &mut left_side);
         }
 
         // 3. Verify arms integrity.
-        Self::verify_arms_integrity(macro_src, &mut arms);
+        Self::verify_arms_integrity(macro_src, &mut arms, &mut errors);
 
         // 4. Generate arms predicates
-        Self::generate_arms_predicate(macro_src, &mut arms);
+        Self::generate_arms_predicate(macro_src, &mut arms, &mut errors);
+
+        // 4.1. Verify that supported targets are covered. Predicates are needed, so only if no error occurred.
+        if errors.is_empty() {
+            Self::verify_supported_targets(macro_src, &arms, &mut errors);
+        }
 
-        // 5. Panic! for arms with @
+        // 5. Return all errors found
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        // 6. Panic! for arms with @
         Self::panic_arms(&arms);
 
-        // 6. Return arms vector
-        arms
+        // 7. Return arms vector
+        Ok(arms)
+
+    }
+
+    /// Generate a hidden `include_bytes!` for each configuration file read so cargo rebuilds the crate when they change.
+    /// 
+    /// Return ts created (empty if no file other than Cargo.toml was read).
+    pub fn generate_tracking_ts() -> TokenStream {
+
+        let mut content = TokenStream::new();
+
+        for file in get_tracked_files() {
+            if let Some(path) = file.to_str() {
+                content.extend(format!("const _ : &[u8] = include_bytes!({:?});", path).parse::<TokenStream>().unwrap());
+            }
+        }
+
+        content
+
+    }
+
+    /// Generate a `compile_error!` guarded to be compiled only when no arm matches, naming the configuration not covered.
+    /// 
+    /// Return ts created (empty if there are no arms).
+    pub fn generate_exhaustive_ts(arms : &[TargetArm]) -> TokenStream {
+
+        let (Some(first), Some(last)) = (arms.first(), arms.last()) else {
+            return TokenStream::new();
+        };
+
+        // 1. Predicates of arms with modifiers applied. Documentation is included in predicates so it never fires with doc.
+        let preds : Vec<String> = arms.iter().map(|arm| arm.cfg_predicate().to_string()).collect();
+        let names : Vec<String> = arms.iter().map(|arm| arm.name()).collect();
+
+        // 2. Generate an error located on arms for each configuration, so the one compiled names the configuration not covered.
+        let mut errors = TokenStream::new();
+        for (pred, uncovered) in Self::get_uncovered_configurations(&preds) {
+            errors.extend(format!("#[cfg({})]", pred).parse::<TokenStream>().unwrap());
+            errors.extend(NSCFGError::NonExhaustiveArms(names.clone(), uncovered).between("", first.span(), last.span()).to_compile_error());
+        }
+
+        // 3. Generate #[cfg] of errors
+        let mut content = format!("#[cfg(not(any({})))] const _ : () = ", preds.join(",")).parse::<TokenStream>().unwrap();
+        content.extend([TokenTree::from(Group::new(Delimiter::Brace, errors)), TokenTree::from(Punct::new(';', Spacing::Alone))]);
+
+        content
+
+    }
+
+    /// Generate a `compile_error!` for each pair of arms, guarded to be compiled only when both arms match.
+    /// 
+    /// Wildcard arm is skipped since it always matches. Documentation includes every arm so errors never fire with doc.
+    /// Return ts created.
+    pub fn generate_disjoint_ts(arms : &[TargetArm]) -> TokenStream {
+
+        let mut content = TokenStream::new();
+        let arms : Vec<&TargetArm> = arms.iter().filter(|arm| !matches!(arm.arm_type, TargetArmType::Wildcard)).collect();
+
+        for (i, arm_a) in arms.iter().enumerate() {
+            for arm_b in arms.iter().skip(i + 1) {
+                // 1. Generate #[cfg] of error from predicates with modifiers applied
+                let pred_ts = if is_nscfg_autodoc() {
+                    format!("all(not({}),{},{})", DOC_ALIAS, arm_a.cfg_predicate(), arm_b.cfg_predicate())
+                } else {
+                    format!("all({},{})", arm_a.cfg_predicate(), arm_b.cfg_predicate())
+                };
+                content.extend(format!("#[cfg({})]", pred_ts).parse::<TokenStream>().unwrap());
+
+                // 2. Generate error located on second arm
+                content.extend(NSCFGError::OverlappingArms(arm_a.name(), arm_b.name()).at("", arm_b.span()).to_compile_error());
+            }
+        }
+
+        content
+
+    }
+
+    /// Get configurations where arms may not match as (predicate, description), splitting keys used by arms into their values.
+    /// 
+    /// Returns a single configuration described by arms predicates if they can't be read or they give too many configurations.
+    fn get_uncovered_configurations(preds : &[String]) -> Vec<(String, String)> {
+
+        // 1. Get leaves of arms predicates. Doc is skipped since arms always match with it.
+        let leaves : Option<Vec<String>> = preds.iter().map(|pred| SyntaxTreeNode::from_cfg(pred.parse::<TokenStream>().unwrap())
+            .map(|node| node.leaves())).collect::<Option<Vec<Vec<String>>>>()
+            .map(|leaves| leaves.concat().into_iter().filter(|leaf| leaf.ne(DOC_ALIAS)).collect());
+
+        // 2. Split configurations according to leaves
+        match leaves.filter(|leaves| !leaves.is_empty()).and_then(|leaves| split_configurations(&leaves, MAX_NAMED_CONFIGURATIONS)) {
+            Some(configurations) => configurations,
+            _ => vec![(String::from(MODIFIER_ACTIVATE_VALUE), format!("not(any({}))", preds.join(",")))],
+        }
+
+    }
+
+    /// Add an extraction error and invalidate arm.
+    /// 
+    /// Only the first extraction error of an arm is kept since the following ones are usually caused by it.
+    #[inline(always)]
+    fn add_error(errors : &mut Vec<NSCFGSpanError>, arm : &mut TargetArm, err : NSCFGSpanError) {
+        if arm.valid {
+            errors.push(err);
+            arm.valid = false;
+        }
 
     }
 
@@ -186,13 +450,13 @@ impl TargetArm {
 
     /// Extract tokens for attributes.
     #[inline(always)]
-    fn extract_attributes(arm : &mut TargetArm, token : TokenTree) {
+    fn extract_attributes(arm : &mut TargetArm, token : TokenTree) -> Result<(), NSCFGSpanError> {
         match token.clone() {
             TokenTree::Group(grp) => {
                 match arm.arm_type {    // Make sure legacy syntax is correct
                     TargetArmType::Legacy => match grp.delimiter() {
                         Delimiter::Bracket => {},
-                        _ => panic!("{}", NSCFGError::LegacySyntaxError.message(token.to_string().as_str())),  // Panic since legacy isn't formatted correctly
+                        _ => return Err(NSCFGError::LegacySyntaxError.at(token.to_string().as_str(), grp.span())),  // Legacy isn't formatted correctly
                     },
                     _ => {},
                 }
@@ -213,13 +477,13 @@ impl TargetArm {
             TokenTree::Punct(punct) => match punct.as_char() {      // Verify syntax key symbol
                 MODIFIER_PANIC => {
                     if !arm.arm_ts.is_empty() {
-                        panic!("{}", NSCFGError::ModifierNotFirst.message(""));  // Modifier is not first character
+                        return Err(NSCFGError::ModifierNotFirst.at("", punct.span()));  // Modifier is not first character
                     }
                     arm.modifier = TargetArmModifier::Panic;
                 },
                 MODIFIER_ACTIVATE => {
                     if !arm.arm_ts.is_empty() {
-                        panic!("{}", NSCFGError::ModifierNotFirst.message(""));  // Modifier is not first character
+                        return Err(NSCFGError::ModifierNotFirst.at("", punct.span()));  // Modifier is not first character
                     }
                     // Debug behaviour. Activate arm.
                     #[cfg(debug_assertions)]
@@ -230,15 +494,18 @@ impl TargetArm {
                     #[cfg(not(debug_assertions))]
                     {
                         match get_release_modifier_behaviour() {
-                            ReleaseModifierBehaviour::Panic => panic!("{}", NSCFGError::ModifierPanicRelease.message("")),  // Modifier release panic
+                            ReleaseModifierBehaviour::Panic => return Err(NSCFGError::ModifierPanicRelease.at("", punct.span())),  // Modifier release panic
                             _ => {},    // Just ignore it
                         }
 
                     }
                 },
+                MODIFIER_DEACTIVATE if matches!(arm.arm_ts.clone().into_iter().last(), Some(TokenTree::Ident(_) | TokenTree::Literal(_))) => {
+                    arm.arm_ts.extend(TokenStream::from(token));    // Hyphen of leaf value like `my-feature:ft`
+                },
                 MODIFIER_DEACTIVATE => {
                     if !arm.arm_ts.is_empty() {
-                        panic!("{}", NSCFGError::ModifierNotFirst.message(""));  // Modifier is not first character
+                        return Err(NSCFGError::ModifierNotFirst.at("", punct.span()));  // Modifier is not first character
                     }
                     // Debug behaviour. Activate arm.
                     #[cfg(debug_assertions)]
@@ -249,7 +516,7 @@ impl TargetArm {
                     #[cfg(not(debug_assertions))]
                     {
                         match get_release_modifier_behaviour() {
-                            ReleaseModifierBehaviour::Panic => panic!("{}", NSCFGError::ModifierPanicRelease.message("")),  // Modifier release panic
+                            ReleaseModifierBehaviour::Panic => return Err(NSCFGError::ModifierPanicRelease.at("", punct.span())),  // Modifier release panic
                             _ => {},    // Just ignore it
                         }
 
@@ -258,30 +525,46 @@ impl TargetArm {
                 },
                 LEGACY_ARM => {
                     if !arm.arm_ts.is_empty() {
-                        panic!("{}", NSCFGError::MixedSyntaxError.message(""));  // Mixed syntax error
+                        return Err(NSCFGError::MixedSyntaxError.at("", punct.span()));  // Mixed syntax error
                     }
                     arm.arm_type = TargetArmType::Legacy;
                     arm.arm_ts.extend(TokenStream::from(token));
                 },
                 NEGATIVE_SYMBOL | AND_SYMBOL | OR_SYMBOL => {   // Verify if mixed syntax
                     match arm.arm_type{
-                        TargetArmType::Legacy => panic!("{}", NSCFGError::MixedSyntaxError.message("")),  // Mixed syntax error
+                        TargetArmType::Legacy => return Err(NSCFGError::MixedSyntaxError.at("", punct.span())),  // Mixed syntax error
                         _ => arm.arm_ts.extend(TokenStream::from(token)),
                     }
 
                 },
-                ARM_SEPARATOR => panic!("{}", NSCFGError::ContentSeparatorMissing.message("")),  // Arm content separator error
-                CONTENT_SEPARATOR_0 | CONTENT_SEPARATOR_1 => {},    // Ignore tokens
+                ARM_SEPARATOR => return Err(NSCFGError::ContentSeparatorMissing.at("", punct.span())),  // Arm content separator error
+                CONTENT_SEPARATOR_0 if punct.spacing() == Spacing::Alone => arm.arm_ts.extend(TokenStream::from(token)),  // Raw `key = "value"` leaf
+                CONTENT_SEPARATOR_1 => arm.arm_ts.extend(TokenStream::from(token)),   // Range leaf `>=32:pw`, separator `>` was handled
+                CONTENT_SEPARATOR_0 => {},    // Ignore tokens
                 _ => arm.arm_ts.extend(TokenStream::from(token)),
             },
             _ => arm.arm_ts.extend(TokenStream::from(token)), // Add token to attributes
         }
+
+        Ok(())
     }
 
 
     /// Extract tokens for content.
+    /// 
+    /// Arm is added when reaching the `,` ending it according to content context.
     #[inline(always)]
-    fn extract_content(arm : &mut TargetArm, arms : &mut Vec<TargetArm>, token : TokenTree, left_side : &mut bool) {
+    fn extract_content(arm : &mut TargetArm, arms : &mut Vec<TargetArm>, token : TokenTree, left_side : &mut bool, context : &mut ContentContext) {
+
+        // 1. End of arm
+        if context.is_arm_end(&token) {
+            Self::add_arm(arms, arm, left_side);
+            *context = ContentContext::new(context.type_only);
+            return;
+        }
+
+        // 2. Update content context
+        context.update(&token);
 
         match token.clone() {
             TokenTree::Group(grp) => arm.content.extend(match grp.delimiter() {
@@ -294,11 +577,7 @@ impl TargetArm {
                 },
                 _ => TokenStream::from(token), // Add token to content
             }),
-            TokenTree::Punct(punct) => match punct.as_char() {
-                ARM_SEPARATOR => Self::add_arm(arms, arm, left_side),    // Add arm to vector.
-                _ => arm.content.extend(TokenStream::from(token)),  // Add content to arm
-            },
-             // Add content to arm
+            // Add content to arm
             _ => arm.content.extend(TokenStream::from(token)),
         }
 
@@ -306,39 +585,39 @@ impl TargetArm {
 
     /// Extract legacy predicates from legacy syntax
     #[inline(always)]
-    fn extract_legacy_predicates(legacy : TokenStream) -> TokenStream {
+    fn extract_legacy_predicates(legacy : TokenStream, span : Span) -> Result<TokenStream, Vec<NSCFGSpanError>> {
 
         for token in legacy.clone() {
             match token {
                 TokenTree::Group(grp) => match grp.delimiter() {
-                    Delimiter::Parenthesis => return grp.stream(),
-                    Delimiter::Bracket => return Self::extract_legacy_predicates(grp.stream()),
+                    Delimiter::Parenthesis => return Ok(grp.stream()),
+                    Delimiter::Bracket => return Self::extract_legacy_predicates(grp.stream(), grp.span()),
                     _ => {},
                 },
                 _ => {}
             }
         }
 
-        panic!("{}", NSCFGError::LegacySyntaxError.message(legacy.to_string().as_str()));  // Panic since legacy isn't formatted correctly
+        Err(vec![NSCFGError::LegacySyntaxError.at(legacy.to_string().as_str(), span)])  // Legacy isn't formatted correctly
 
     }
 
     /// Process tokens punctuation and determine if left side or right side.
     /// 
-    /// Returns true if token was handled.
+    /// Returns Ok(true) if token was handled.
     /// 
-    /// Panic(s)
-    /// Will panic! if arm separator comma is missing.
+    /// Error(s)
+    /// Returns Err([NSCFGSpanError]) if arm separator comma is missing or content separator is malformed.
     #[inline(always)]
-    fn handle_arm_separator(arm : &mut TargetArm, token : TokenTree, left_side : &mut bool, separator : &mut bool) -> bool {
+    fn handle_arm_separator(arm : &mut TargetArm, token : TokenTree, left_side : &mut bool, separator : &mut bool) -> Result<bool, NSCFGSpanError> {
         match token.clone() {
             TokenTree::Punct(punct) => {
                 match punct.as_char(){
                     CONTENT_SEPARATOR_0 => {
                         if *separator && *left_side {   // Double == in left side
-                            panic!("{}", NSCFGError::ContentSeparatorError.message(""));
+                            return Err(NSCFGError::ContentSeparatorError.at("", punct.span()));
                         } else {
-                            *separator = true;
+                            *separator = punct.spacing() == Spacing::Joint;     // Only `=>` written together is a separator
                         }
                     },
                     CONTENT_SEPARATOR_1 => {
@@ -347,13 +626,13 @@ impl TargetArm {
                             *separator = false;
 
                             if *left_side && !arm.content.is_empty() {    // Missing comma `,` arm separator.
-                                panic!("{}", NSCFGError::ArmSeparatorMissing.message(""));
+                                return Err(NSCFGError::ArmSeparatorMissing.at("", punct.span()));
                             }
 
-                            return true;
+                            return Ok(true);
                         } else {
-                            if *left_side { // Missing = before >
-                                panic!("{}", NSCFGError::ContentSeparatorError.message(""));
+                            if *left_side && !Self::is_range_operator(arm, &punct) { // Missing = before >
+                                return Err(NSCFGError::ContentSeparatorError.at("", punct.span()));
                             }
                         }                        
                     },
@@ -363,7 +642,17 @@ impl TargetArm {
             _ => *separator = false // Reset separator
         }
 
-        false
+        Ok(false)
+    }
+
+    /// Returns true if `>` on left side starts a range leaf like `>=32:pw` or `>32:pw` instead of being a malformed separator.
+    #[inline(always)]
+    fn is_range_operator(arm : &TargetArm, punct : &Punct) -> bool {
+        punct.spacing() == Spacing::Joint || match arm.arm_ts.clone().into_iter().last() {
+            None => true,
+            Some(TokenTree::Punct(previous)) => matches!(previous.as_char(), AND_SYMBOL | OR_SYMBOL | NEGATIVE_SYMBOL),
+            _ => false,
+        }
     }
 
     /// Add arm to arms vector according to macro source.
@@ -380,25 +669,45 @@ impl TargetArm {
 
     /// Generate arms predicate used to generate configuration tokenstream.
     #[inline(always)]
-    fn generate_arms_predicate(macro_src : NscfgMacroSource, arms : &mut Vec<TargetArm>){
+    fn generate_arms_predicate(macro_src : NscfgMacroSource, arms : &mut Vec<TargetArm>, errors : &mut Vec<NSCFGSpanError>) {
 
         // Each macro has different predicates behaviour
         match macro_src {
-            NscfgMacroSource::TargetMacro => {
+            NscfgMacroSource::TargetMacro(TargetOptions { first : false, .. }) | NscfgMacroSource::StmtMacro => {  // Any matching arm is included
+                let is_target = matches!(macro_src, NscfgMacroSource::TargetMacro(_));
+
                 // For each arm
-                arms.iter_mut().for_each(|arm| {
-                    // 1. Generate predicate_ts
-                    arm.pred_ts.extend(Self::generate_pred_ts(arm.arm_type, arm.arm_ts.clone()));
+                for arm in arms.iter_mut() {
+                    // 1. Generate predicate_ts. Invalid arms are skipped.
+                    if !arm.valid {
+                        continue;
+                    }
+                    match Self::generate_pred_ts(arm.arm_type, arm.arm_ts.clone(), arm.span()) {
+                        Ok(pred_ts) => arm.pred_ts.extend(pred_ts),
+                        Err(err) => {
+                            errors.extend(err);
+                            continue;
+                        },
+                    }
+
+                    // 2. Generate cfg_ts according to modifier. Items of target_cfg! get doc while statements aren't documented.
+                    if is_target {
+                        arm.cfg_ts.extend(Self::generate_target_cfg_ts(Self::set_default_doc(arm.pred_ts.clone()), arm.modifier));
 
-                    // 2. Generate cfg_ts with doc according to modifier
-                    arm.cfg_ts.extend(Self::generate_target_cfg_ts(Self::set_default_doc(arm.pred_ts.clone()), arm.modifier));
+                        // 3. Generate attr_ts
+                        arm.attr_ts.extend(Self::generate_target_attr_ts(arm.pred_ts.clone()));
+                    } else {
+                        arm.cfg_ts.extend(Self::generate_target_cfg_ts(arm.pred_ts.clone(), arm.modifier));
+                    }
 
-                    // 3. Generate attr_ts
-                    arm.attr_ts.extend(Self::generate_target_attr_ts(arm.pred_ts.clone()));
-                });
+                    // 4. Generate warning_ts
+                    arm.warning_ts.extend(Self::generate_warning_ts(arm.arm_type, arm.arm_ts.clone()));
+                }
 
             },
-            NscfgMacroSource::MatchMacro => {
+            _ => {  // match_cfg!, type_cfg!, cfg_consts! and target_cfg! with `first` option only include the first matching arm.
+                let is_item = matches!(macro_src, NscfgMacroSource::TargetMacro(_) | NscfgMacroSource::ConstsMacro);
+
                 // Debug behaviour. Set modifiers.
                 #[cfg(debug_assertions)]
                 {
@@ -418,16 +727,34 @@ impl TargetArm {
                 let mut cumul_ts = MODIFIER_ACTIVATE_VALUE.parse::<TokenStream>().unwrap();
 
                 // For each arm
-                arms.iter_mut().for_each(|arm| {
-                    // 1. Generate predicate_ts
-                    arm.pred_ts.extend(Self::generate_pred_ts(arm.arm_type, arm.arm_ts.clone()));
+                for arm in arms.iter_mut() {
+                    // 1. Generate predicate_ts. Invalid arms are skipped.
+                    if !arm.valid {
+                        continue;
+                    }
+                    match Self::generate_pred_ts(arm.arm_type, arm.arm_ts.clone(), arm.span()) {
+                        Ok(pred_ts) => arm.pred_ts.extend(pred_ts),
+                        Err(err) => {
+                            errors.extend(err);
+                            continue;
+                        },
+                    }
 
-                    // 2. Generate pred_ts from cumulatives tokenstream according to arm type
-                    let pred_ts = format!("all({},{})", cumul_ts, arm.pred_ts.clone()).parse::<TokenStream>().unwrap();
+                    // 2. Generate pred_ts from cumulatives tokenstream according to arm type. Items of target_cfg! and cfg_consts! get doc so the first arm is documented.
+                    let arm_pred_ts = if is_item { Self::set_default_doc(arm.pred_ts.clone()) } else { arm.pred_ts.clone() };
+                    let pred_ts = format!("all({},{})", cumul_ts, arm_pred_ts).parse::<TokenStream>().unwrap();
 
                     // 3. Generate cfg_ts according to modifier and pred_ts
                     arm.cfg_ts.extend(Self::generate_target_cfg_ts(pred_ts.clone(), arm.modifier));
 
+                    // 3.1. Generate warning_ts
+                    arm.warning_ts.extend(Self::generate_warning_ts(arm.arm_type, arm.arm_ts.clone()));
+
+                    // 3.2. Generate attr_ts for items. Wildcard arm has no predicate to tag.
+                    if is_item && !matches!(arm.arm_type, TargetArmType::Wildcard) {
+                        arm.attr_ts.extend(Self::generate_target_attr_ts(arm.pred_ts.clone()));
+                    }
+
                     // 4. Cumulate tokenstream for arm exclusivity.
                     cumul_ts.extend(format!(", not({})", { // Wish I could use match_cfg! here =(
                         #[cfg(debug_assertions)]
@@ -435,15 +762,15 @@ impl TargetArm {
                             match arm.modifier {
                                 TargetArmModifier::Activate => MODIFIER_ACTIVATE_VALUE.parse::<TokenStream>().unwrap(),
                                 TargetArmModifier::Deactivate => MODIFIER_DEACTIVATE_VALUE.parse::<TokenStream>().unwrap(),
-                                _ => arm.pred_ts.clone(),
+                                _ => arm_pred_ts,
                             }
                         }
                         #[cfg(not(debug_assertions))]
                         {
-                            arm.pred_ts.clone()
+                            arm_pred_ts
                         }
                     }).parse::<TokenStream>().unwrap());
-                });
+                }
             },
         }
     }
@@ -472,17 +799,48 @@ impl TargetArm {
     /// Generate predicate tokenstream for arm.
     /// Return ts created.
     #[inline(always)]
-    fn generate_pred_ts(arm_type : TargetArmType, arm_ts : TokenStream) -> TokenStream {
+    fn generate_pred_ts(arm_type : TargetArmType, arm_ts : TokenStream, span : Span) -> Result<TokenStream, Vec<NSCFGSpanError>> {
 
         match arm_type{
             TargetArmType::Simplified => {
-                let syntax_tree = SyntaxTreeNode::generate(arm_ts); // Simplified predicates comes from syntax tree
-                syntax_tree.to_string().parse::<TokenStream>().unwrap()
+                let syntax_tree = SyntaxTreeNode::generate(arm_ts, span)?; // Simplified predicates comes from syntax tree
+                Ok(syntax_tree.to_string().parse::<TokenStream>().unwrap())
             },
             TargetArmType::Legacy => {
-                Self::extract_legacy_predicates(arm_ts)
+                Self::extract_legacy_predicates(arm_ts, span)
             },
-            TargetArmType::Wildcard => MODIFIER_ACTIVATE_VALUE.parse::<TokenStream>().unwrap(),  // Wildcard pred_ts is MODIFIER_ACTIVATE_VALUE
+            TargetArmType::Wildcard => Ok(MODIFIER_ACTIVATE_VALUE.parse::<TokenStream>().unwrap()),  // Wildcard pred_ts is MODIFIER_ACTIVATE_VALUE
+        }
+
+    }
+
+    /// Write tokens without spaces except around `&` and `|`.
+    fn compact_ts(stream : TokenStream) -> String {
+        stream.into_iter().map(|token| match token {
+            TokenTree::Group(grp) => match grp.delimiter() {
+                Delimiter::Parenthesis => format!("({})", Self::compact_ts(grp.stream())),
+                Delimiter::Bracket => format!("[{}]", Self::compact_ts(grp.stream())),
+                Delimiter::Brace => format!("{{{}}}", Self::compact_ts(grp.stream())),
+                Delimiter::None => Self::compact_ts(grp.stream()),
+            },
+            TokenTree::Punct(punct) if punct.as_char() == AND_SYMBOL || punct.as_char() == OR_SYMBOL => format!(" {} ", punct.as_char()),
+            token => token.to_string(),
+        }).collect()
+    }
+
+    /// Generate warning tokenstream for arm.
+    /// 
+    /// Stable proc macros can't emit warnings, so a deprecated struct is used to display the message.
+    /// Return ts created (empty if no warning).
+    #[inline(always)]
+    fn generate_warning_ts(arm_type : TargetArmType, arm_ts : TokenStream) -> TokenStream {
+
+        match arm_type {
+            TargetArmType::Simplified => match SyntaxTreeNode::precedence_warning(arm_ts) {
+                Some(warning) => format!("const _ : () = {{ #[deprecated(note = {:?})] struct NscfgWarning; let _ = NscfgWarning; }};", warning.message()).parse::<TokenStream>().unwrap(),
+                None => TokenStream::new(),
+            },
+            _ => TokenStream::new(),
         }
 
     }
@@ -543,94 +901,165 @@ impl TargetArm {
     /// CfgBoostError::EmptyArm
     /// CfgBoostError::WildcardArmOnTarget
     /// CfgBoostError::TargetInFunction
-    /// CfgBoostError::WildcardArmMissing
+    /// CfgBoostError::WildcardArmMissing (if no supported targets are declared)
+    /// CfgBoostError::ConstExpected
+    /// CfgBoostError::ConstMissing
     /// CfgBoostError::MatchModifierMoreThanOneActivate
     /// CfgBoostError::MatchDeactivatedWildArm
     #[inline(always)]
-    fn verify_arms_integrity(macro_src : NscfgMacroSource, arms: &mut Vec<TargetArm>) {
+    fn verify_arms_integrity(macro_src : NscfgMacroSource, arms: &mut [TargetArm], errors : &mut Vec<NSCFGSpanError>) {
 
         match macro_src {
-            NscfgMacroSource::TargetMacro => {
-                if Self::has_wild_arm(&arms){  // Single macro doesn't accept wildcard arms!
-                    panic!("{}", NSCFGError::WildcardArmOnTarget.message(""));
+            NscfgMacroSource::TargetMacro(options) => {
+                if let Some(wild_arm) = Self::get_wild_arm(arms, errors) {  // Single macro doesn't accept wildcard arms unless only the first matching arm is included!
+                    if !options.first {
+                        errors.push(NSCFGError::WildcardArmOnTarget(String::from("target_cfg")).at("", wild_arm.span()));
+                    }
                 }
 
-                // // If any arm is inside a function, panic!
-                for arm in arms {
-                    if Self::is_inside_function(arm) {  
-                        panic!("{}", NSCFGError::TargetInFunction.message(""));
+                // If any arm content can't be items, target_cfg! is inside a function
+                for arm in arms.iter_mut().filter(|arm| arm.valid) {
+                    if let Some((token, span)) = item::find_statement(arm.content.clone()) {
+                        errors.push(NSCFGError::TargetInFunction(token).at("", span));
                     }
-                    if arm.arm_ts.is_empty() {  // Arms ts must not be empty
-                        panic!("{}", NSCFGError::EmptyArm.message(""));
+                    if arm.arm_ts.is_empty() && !matches!(arm.arm_type, TargetArmType::Wildcard) {  // Arms ts must not be empty
+                        errors.push(NSCFGError::EmptyArm.at("", arm.span()));
+                        arm.valid = false;
                     } 
                 }
             },
-            _ => {  
-                if !Self::has_wild_arm(&arms){  // Make sure a wildcard arm is written.
-                    panic!("{}", NSCFGError::WildcardArmMissing.message(""));
+            NscfgMacroSource::StmtMacro => {
+                if let Some(wild_arm) = Self::get_wild_arm(arms, errors) {  // Wildcard arm would always be included
+                    errors.push(NSCFGError::WildcardArmOnTarget(String::from("stmt_cfg")).at("", wild_arm.span()));
+                }
+
+                for arm in arms.iter_mut().filter(|arm| arm.valid && arm.arm_ts.is_empty() && !matches!(arm.arm_type, TargetArmType::Wildcard)) {  // Arms ts must not be empty
+                    errors.push(NSCFGError::EmptyArm.at("", arm.span()));
+                    arm.valid = false;
+                }
+            },
+            NscfgMacroSource::MatchMacro | NscfgMacroSource::TypeMacro | NscfgMacroSource::ConstsMacro => {  
+                // Make sure a wildcard arm is written. An invalid arm may be hiding it, so it's only verified if all arms are valid.
+                // Not needed when supported targets are declared since arms must cover them.
+                if Self::get_wild_arm(arms, errors).is_none() && arms.iter().all(|arm| arm.valid) && get_supported_targets().is_empty() {
+                    errors.push(NSCFGError::WildcardArmMissing.at("", Span::call_site()));
                 }
 
                 // Test for more than 1 activated arm
                 let mut activated:usize  = 0;
 
-                arms.iter().for_each(|arm| match arm.modifier {
-                    TargetArmModifier::Activate => activated += 1,  // Increment activated arms
-                    TargetArmModifier::Deactivate => match arm.arm_type{
-                        TargetArmType::Wildcard => panic!("{}", NSCFGError::MatchDeactivatedWildArm.message("")),    // Wildcard arm cannot be deativated
+                for arm in arms.iter() {
+                    match arm.modifier {
+                        TargetArmModifier::Activate => {
+                            activated += 1;  // Increment activated arms
+                            if activated > 1 {  // Cannot have more than 1 activated in match_cfg!
+                                errors.push(NSCFGError::MatchModifierMoreThanOneActivate.at("", arm.span()));
+                            }
+                        },
+                        TargetArmModifier::Deactivate => match arm.arm_type{
+                            TargetArmType::Wildcard => errors.push(NSCFGError::MatchDeactivatedWildArm.at("", arm.span())),    // Wildcard arm cannot be deativated
+                            _ => {},
+                        },
                         _ => {},
-                    },
-                    _ => {},
-                });
+                    }
+                }
 
-                if activated > 1 {  // Cannot have more than 1 activated in match_cfg!
-                    panic!("{}", NSCFGError::MatchModifierMoreThanOneActivate.message(""));
+                // Each arm of cfg_consts! must define the same consts
+                if let NscfgMacroSource::ConstsMacro = macro_src {
+                    Self::verify_consts(arms, errors);
                 }
             }
         }
 
-        
+    }
+
+    /// Verify that cfg_consts! arms only contain consts and that each arm defines the same names.
+    /// 
+    /// Error(s)
+    /// Add [NSCFGError::ConstExpected] for each item that isn't a const and [NSCFGError::ConstMissing] for each name missing from an arm.
+    #[inline(always)]
+    fn verify_consts(arms : &[TargetArm], errors : &mut Vec<NSCFGSpanError>) {
+
+        // 1. Get const names of each valid arm
+        let arms : Vec<(&TargetArm, Vec<String>)> = arms.iter().filter(|arm| arm.valid).map(|arm| (arm, item::split_items(arm.content.clone()).into_iter()
+            .filter_map(|item| match item::const_name(item) {
+                Ok(name) => Some(name),
+                Err((token, span)) => {
+                    errors.push(NSCFGError::ConstExpected(token).at("", span));
+                    None
+                },
+            }).collect())).collect();
+
+        // 2. Names defined by any arm must be defined by each arm
+        let mut names : Vec<&String> = Vec::new();
+        for name in arms.iter().flat_map(|(_, arm_names)| arm_names) {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+
+        for (arm, arm_names) in arms.iter() {
+            for name in names.iter().filter(|name| !arm_names.contains(name)) {
+                errors.push(NSCFGError::ConstMissing(arm.name(), name.to_string()).at("", arm.span()));
+            }
+        }
 
     }
 
-    /// Returns true if arm of macro is inside a function.
+    /// Verify that arms of match_cfg! and exhaustive target_cfg! cover each supported target.
     /// 
-    /// This function tries to detect `let` and flow of control keywords to determine if inside or not.
+    /// A target is covered if an arm is true for it, with arm modifiers applied. Arms depending on keys the target can't decide, like `feature`, don't cover it.
     /// 
-    /// Since accuracy isn't 100%, it isn't used to validate that match_cfg! is inside a function. Only to detect if target_cfg! is.
+    /// Error(s)
+    /// Add [NSCFGError::UncoveredTargets] to errors with each supported target not covered.
     #[inline(always)]
-    fn is_inside_function(arm: &TargetArm) -> bool {
+    fn verify_supported_targets(macro_src : NscfgMacroSource, arms : &[TargetArm], errors : &mut Vec<NSCFGSpanError>) {
 
-        for t in arm.content.clone() {
-            match t {
-                proc_macro::TokenTree::Ident(ident) => match ident.to_string().as_str() {
-                    "let" | "if" | "else" | "loop" | "break" | "while" | "for" | "match" | "println" | "panic"   => return true,    // Those keyword are only found inside functions.
-                    _ => {},
-                },
-                _ => {},
-            }
+        match macro_src {
+            NscfgMacroSource::TargetMacro(options) if !options.exhaustive => return,
+            NscfgMacroSource::StmtMacro => return,
+            _ => {},
+        }
+
+        // 1. Get arms predicates with modifiers applied as syntax tree
+        let nodes : Vec<Node> = arms.iter().filter_map(|arm| SyntaxTreeNode::from_cfg(arm.cfg_predicate())).collect();
+
+        // 2. Get supported targets not covered
+        let uncovered : Vec<String> = get_supported_targets().iter().map(|triple| TargetCfg::from_triple(triple))
+            .filter(|target| !nodes.iter().any(|node| node.evaluate(target) == Some(true)))
+            .map(|target| target.triple).collect();
+
+        if !uncovered.is_empty() {
+            let start = arms.first().map(|arm| arm.span()).unwrap_or_else(Span::call_site);
+            let end = arms.last().map(|arm| arm.span()).unwrap_or_else(Span::call_site);
+            errors.push(NSCFGError::UncoveredTargets(uncovered).between("", start, end));
         }
 
-        false
     }
 
-    /// Returns true if a Wild arm is in arms vector.
+    /// Returns the wildcard arm if one is in arms vector.
+    /// 
+    /// Error(s)
+    /// Add [NSCFGError::WildcardArmNotLast] to errors for each wildcard arm that isn't the last arm.
     #[inline(always)]
-    fn has_wild_arm(arms : &Vec<TargetArm>) -> bool {
+    fn get_wild_arm<'a>(arms : &'a [TargetArm], errors : &mut Vec<NSCFGSpanError>) -> Option<&'a TargetArm> {
 
-        let mut has_wc = false;
+        let mut wild_arm : Option<&TargetArm> = None;
 
-        for arm in arms {
-            if has_wc { // Panic since wildcard arm isn't last.
-                panic!("{}", NSCFGError::WildcardArmNotLast.message(""));
-            }
+        for (i, arm) in arms.iter().enumerate() {
             match arm.arm_type {
-                TargetArmType::Wildcard => has_wc = true,
+                TargetArmType::Wildcard => {
+                    if i + 1 < arms.len() { // Wildcard arm isn't last.
+                        errors.push(NSCFGError::WildcardArmNotLast.at("", arm.span()));
+                    }
+                    wild_arm = Some(arm);
+                },
                 _ => {},
             }
         }
 
-        // If no match, return false
-        has_wc
+        // If no match, return None
+        wild_arm
     }
 
-}
+}
\ No newline at end of file
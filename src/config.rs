@@ -22,9 +22,9 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
 
-use std::{env, path::Path, fs};
+use std::{env, path::{Path, PathBuf}, fs, rc::Rc, cell::RefCell};
 
-use crate::errors::NSCFGError;
+use crate::{errors::NSCFGError, toml::{parse_toml, get_toml_table, get_toml_value, has_toml_table, TomlEntries, TomlValue}};
 
 #[cfg(test)]
 #[path = "../tests/unit/config.rs"]
@@ -34,12 +34,41 @@ mod unit_tests; // Unit tests located in tests folder
 pub(crate) const ENV_KEY_PREDICATE : &str = "nscfg_predicate-";   // Key used to fetch custom predicate
 pub(crate) const ENV_KEY_ALIAS : &str = "nscfg-";                 // Key used to fetch custom aliases
 pub(crate) const PREDICATE_PLACEHOLDER : &str = "{}";               // Predicate placeholder
+const ALIAS_PARAMS_START : char = '(';                          // Start of parameters of a parametrized alias (`simd(arch, feat)`)
+const ALIAS_PARAMS_END : char = ')';                            // End of parameters of a parametrized alias
+const ALIAS_PARAMS_SEPARATOR : char = ',';                      // Separator of parameters of a parametrized alias
+const ALIAS_PLACEHOLDER_START : char = '{';                     // Start of a parameter placeholder in alias content (`{arch}:ar`)
+const ALIAS_PLACEHOLDER_END : char = '}';                       // End of a parameter placeholder in alias content
+const ENV_KEY_SCOPE : char = '@';                               // Separator of crate name in crate-scoped keys (`nscfg@mycrate-gpu`)
 const AUTO_DOC_KEY : &str = "nscfg_autodoc";                    // Key for nscfg autodocumentation parameter.
+const STRICT_KEY : &str = "nscfg_strict";                       // Key for nscfg strict mode parameter.
+pub(crate) const MAX_ALIAS_DEPTH_KEY : &str = "nscfg_max_alias_depth";  // Key for nscfg maximum alias expansion depth parameter.
+const MAX_ALIAS_DEPTH : usize = 32;                             // Default maximum alias expansion depth.
+const TARGETS_KEY : &str = "nscfg_targets";                     // Key for nscfg supported targets parameter.
+const TARGETS_SEPARATOR : char = ',';                           // Separator of supported targets in environment variable.
 const MODIFIER_BEHAVIOUR_KEY : &str = "nscfg_release_modifier_behaviour";                    // Key for nscfg release modifier behaviour parameter.
-const NSCFG_CARGO_CACHE : &str = "CFG_BOOST_ATTR_DOC_SET";      // Key value of cargo.toml caching.
 const NSCFG_DOCRS_TAG : &str = "[package.metadata.docs.rs]";    // Tag to search in Cargo.toml
+const NSCFG_METADATA_TABLE : [&str; 3] = ["package", "metadata", "nscfg"];    // Cargo.toml table of nscfg configuration
+const NSCFG_WORKSPACE_TABLE : [&str; 3] = ["workspace", "metadata", "nscfg"];  // Workspace Cargo.toml table of nscfg configuration
+const WORKSPACE_TABLE : [&str; 1] = ["workspace"];                  // Cargo.toml table of workspace root
+const WORKSPACE_HEADER : &str = "[workspace";                        // Start of workspace table headers, used when manifest can't be parsed
+const PACKAGE_WORKSPACE_KEY : [&str; 2] = ["package", "workspace"]; // Cargo.toml key of explicit workspace root path
+const METADATA_STRICT_KEY : &str = "strict";                        // Cargo.toml key of strict mode parameter
+const METADATA_MAX_ALIAS_DEPTH_KEY : &str = "max_alias_depth";      // Cargo.toml key of maximum alias expansion depth parameter
+const METADATA_TARGETS_KEY : &str = "targets";                      // Cargo.toml key of supported targets parameter
+const METADATA_INHERIT_KEY : &str = "inherit";                      // Cargo.toml key of workspace configuration inheritance
+const METADATA_INCLUDE_KEY : &str = "include";                      // Cargo.toml key of alias packs to include
+const NSCFG_LOCAL_FILE : &str = "nscfg.toml";                       // nscfg configuration file next to Cargo.toml
+const METADATA_ALIASES_KEY : &str = "aliases";                      // Cargo.toml key of aliases table
+const METADATA_PREDICATES_KEY : &str = "predicates";                // Cargo.toml key of predicates table
+const METADATA_AUTODOC_KEY : &str = "autodoc";                      // Cargo.toml key of autodocumentation parameter
+const METADATA_MODIFIER_BEHAVIOUR_KEY : &str = "release_modifier_behaviour";    // Cargo.toml key of release modifier behaviour parameter
 const CARGO_MANIFEST_DIR : &str = "CARGO_MANIFEST_DIR";             // Cargo manifest dir key
 const CARGO_MANIFEST_NAME : &str = "Cargo.toml";                    // Cargo manifest file name
+const CARGO_PKG_NAME : &str = "CARGO_PKG_NAME";                     // Cargo package name key
+const NSCFG_CRATE_NAME : &str = "nscfg";                            // Name of nscfg crate
+const DEPENDENCIES_TABLE_SUFFIX : &str = "dependencies";            // Suffix of Cargo.toml dependencies tables (build-dependencies, ...)
+const DEPENDENCY_PACKAGE_KEY : &str = "package";                    // Cargo.toml key of renamed dependency package
 pub(crate) const DOC_ALIAS : &str = "doc";                          // Doc alias
 
 // Aliases
@@ -74,6 +103,104 @@ pub(crate) const PREDICATES : [(&str, &str); 12] = [
     ("_", PREDICATE_PLACEHOLDER)                // Wildcard predicate
 ];
 
+/// Predicates with a finite domain accepting range leaves like `>=32:pw`.
+pub(crate) const RANGE_DOMAINS : [(&str, &[&str]); 2] = [
+    ("pw", &["16", "32", "64"]),                        // Target pointer width domain
+    ("at", &["8", "16", "32", "64", "128", "ptr"]),     // Target has atomic domain
+];
+
+/// Range domain value compared as the target pointer width, like `ptr:at`.
+pub(crate) const RANGE_POINTER_VALUE : &str = "ptr";
+
+/// Predicate of the target pointer width domain.
+pub(crate) const RANGE_POINTER_PREDICATE : &str = "pw";
+
+/// Where an alias or a predicate is defined.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum DefinitionSource {
+    /// Predefined in nscfg
+    BuiltIn,
+
+    /// Defined in environment variables (usually config.toml [env])
+    Env,
+
+    /// Defined in Cargo.toml [package.metadata.nscfg]
+    Config,
+
+    /// Defined in source code with cfg_alias!
+    Source,
+}
+
+impl DefinitionSource {
+    /// Name of the source written in messages.
+    pub fn name(&self) -> &str {
+        match self {
+            DefinitionSource::BuiltIn => "built-in",
+            DefinitionSource::Env => "env",
+            DefinitionSource::Config => "config",
+            DefinitionSource::Source => "in-source",
+        }
+    }
+}
+
+/// nscfg configuration read from Cargo.toml [package.metadata.nscfg] and [workspace.metadata.nscfg].
+#[derive(Default)]
+pub(crate) struct NscfgConfig {
+    pub aliases : Vec<(String, String)>,                // Custom aliases
+    pub predicates : Vec<(String, String)>,             // Custom predicates
+    pub autodoc : Option<bool>,                         // Autodocumentation parameter
+    pub release_modifier_behaviour : Option<String>,    // Release modifier behaviour parameter
+    pub strict : Option<bool>,                          // Strict mode parameter
+    pub max_alias_depth : Option<usize>,                // Maximum alias expansion depth parameter
+    pub targets : Option<Vec<String>>,                  // Supported target triples parameter
+    pub inherit : Option<bool>,                         // Inherit workspace configuration parameter
+    pub include : Vec<PathBuf>,                         // Alias packs to include
+    pub files : Vec<PathBuf>,                           // nscfg.toml and packs read, tracked so cargo rebuilds when they change
+}
+
+impl NscfgConfig {
+    /// Inherit configuration of workspace. Entries already defined have priority over the workspace ones.
+    pub fn inherit_from(&mut self, workspace : NscfgConfig) {
+        self.aliases.extend(workspace.aliases);
+        self.predicates.extend(workspace.predicates);
+        self.autodoc = self.autodoc.or(workspace.autodoc);
+        self.strict = self.strict.or(workspace.strict);
+        self.max_alias_depth = self.max_alias_depth.or(workspace.max_alias_depth);
+        self.targets = self.targets.take().or(workspace.targets);
+        self.release_modifier_behaviour = self.release_modifier_behaviour.take().or(workspace.release_modifier_behaviour);
+        self.include.extend(workspace.include);
+        self.files.extend(workspace.files);
+    }
+
+    /// Make included packs path relative to directory of the file that defines them.
+    pub fn resolve_include(&mut self, file_path : &Path) {
+        let dir = file_path.parent().unwrap_or(Path::new(""));
+        self.include = self.include.iter().map(|pack| dir.join(pack)).collect();
+    }
+}
+
+/// Cargo.toml of the crate being compiled. Read once and cached.
+struct Manifest {
+    dir : String,                               // Manifest directory, used to validate cache
+    docsrs : bool,                              // True if [package.metadata.docs.rs] is present
+    config : Result<NscfgConfig, String>,       // nscfg configuration or error message
+    crate_path : String,                        // Path of nscfg crate, which can be renamed as dependency
+}
+
+/// Names and values of environment variables with parameters in their name.
+type EnvDefinitions = Rc<Vec<(String, String)>>;
+
+thread_local! {
+    // Manifest cache. Kept per manifest directory since a proc macro server can expand many crates.
+    static MANIFEST : RefCell<Option<Rc<Manifest>>> = const { RefCell::new(None) };
+
+    // Aliases defined with cfg_alias! given to the macro being expanded.
+    static SOURCE_ALIASES : RefCell<Vec<(String, String)>> = const { RefCell::new(Vec::new()) };
+
+    // Parametrized definitions of environment variables read by the macro being expanded.
+    static ENV_DEFINITIONS : RefCell<Option<EnvDefinitions>> = const { RefCell::new(None) };
+}
+
 pub(crate) enum ReleaseModifierBehaviour {
     /// Panic! when trying to use modifiers on releae
     Panic,
@@ -86,18 +213,24 @@ pub(crate) enum ReleaseModifierBehaviour {
 /// 
 /// Modifiers are usually used for quick testing and create a different debug behaviour vs release.
 /// By default, panic is used so user know he forgot some modifier. This behaviour can be changed
-/// in config.toml to just ignore the modifiers and not panic. This must be done manually so the user
+/// in config.toml or Cargo.toml to just ignore the modifiers and not panic. This must be done manually so the user
 /// can acknowledge the risk.
 #[allow(dead_code)]
 pub(crate) fn get_release_modifier_behaviour() -> ReleaseModifierBehaviour{
 
-    match std::env::var(MODIFIER_BEHAVIOUR_KEY) {
-        Ok(value) => match value.as_str() {
+    // Environment variable has priority over Cargo.toml
+    let value = match std::env::var(MODIFIER_BEHAVIOUR_KEY) {
+        Ok(value) => Some(value),
+        Err(_) => get_manifest().config.as_ref().ok().and_then(|config| config.release_modifier_behaviour.clone()),
+    };
+
+    match value {
+        Some(value) => match value.as_str() {
             "panic" => ReleaseModifierBehaviour::Panic,
             "ignore" => ReleaseModifierBehaviour::Ignore,
             _ => ReleaseModifierBehaviour::Panic,  // Any other value is considered panic.
         },
-        Err(_) => ReleaseModifierBehaviour::Panic,     // If not set, return panic as default
+        None => ReleaseModifierBehaviour::Panic,     // If not set, return panic as default
     }
 
 }
@@ -113,46 +246,422 @@ pub(crate) fn is_nscfg_autodoc() -> bool {
             "false" => false,
             _ => true,  // Any other value is considered true.
         },
-        Err(_) => true,     // If not set, return true as default
+        // Not set in environment, look in Cargo.toml. Default is true.
+        Err(_) => get_manifest().config.as_ref().ok().and_then(|config| config.autodoc).unwrap_or(true),
+    }
+}
+
+/// Get if strict mode is true or false.
+/// 
+/// In strict mode, global environment keys (`nscfg-gpu`) are ignored and only crate-scoped keys (`nscfg@mycrate-gpu`) are used.
+/// If not set, default is false.
+#[inline(always)]
+pub(crate) fn is_nscfg_strict() -> bool {
+    match std::env::var(STRICT_KEY) {
+        Ok(value) => match value.as_str() {
+            "true" => true,
+            "false" => false,
+            _ => false,  // Any other value is considered false.
+        },
+        // Not set in environment, look in Cargo.toml. Default is false.
+        Err(_) => get_manifest().config.as_ref().ok().and_then(|config| config.strict).unwrap_or(false),
+    }
+}
+
+/// Get maximum depth of alias expansion.
+/// 
+/// If not set or invalid, default is 32.
+#[inline(always)]
+pub(crate) fn get_max_alias_depth() -> usize {
+    match std::env::var(MAX_ALIAS_DEPTH_KEY) {
+        Ok(value) => value.parse().unwrap_or(MAX_ALIAS_DEPTH),
+        // Not set in environment, look in Cargo.toml.
+        Err(_) => get_manifest().config.as_ref().ok().and_then(|config| config.max_alias_depth).unwrap_or(MAX_ALIAS_DEPTH),
+    }
+}
+
+/// Get target triples officially supported by the crate.
+/// 
+/// Arms of match_cfg! and exhaustive target_cfg! must cover each of them. If not set, no target is declared.
+pub(crate) fn get_supported_targets() -> Vec<String> {
+    match std::env::var(TARGETS_KEY) {
+        Ok(value) => value.split(TARGETS_SEPARATOR).map(|target| target.trim()).filter(|target| !target.is_empty()).map(String::from).collect(),
+        // Not set in environment, look in Cargo.toml.
+        Err(_) => get_manifest().config.as_ref().ok().and_then(|config| config.targets.clone()).unwrap_or_default(),
+    }
+}
+
+/// Get the environment keys prefixes of env_key in priority order.
+/// 
+/// Crate-scoped key (`nscfg@mycrate-`) is first, followed by global key (`nscfg-`) if not strict.
+pub(crate) fn get_env_keys(env_key : &str, strict : bool) -> Vec<String> {
+
+    let mut keys : Vec<String> = Vec::new();
+
+    // 1. Crate-scoped key
+    if let Ok(name) = env::var(CARGO_PKG_NAME) {
+        keys.push(format!("{}{}{}-", env_key.trim_end_matches('-'), ENV_KEY_SCOPE, name));
     }
+
+    // 2. Global key
+    if !strict {
+        keys.push(String::from(env_key));
+    }
+
+    keys
+
+}
+
+/// Get the environment key prefix to suggest when a name isn't found.
+/// 
+/// Global key unless strict mode is on, then crate-scoped key.
+pub(crate) fn get_env_key_hint(env_key : &str) -> String {
+    get_env_keys(env_key, is_nscfg_strict()).pop().unwrap_or(String::from(env_key))
+}
+
+/// Get value of name defined in environment variables, looking at crate-scoped key first.
+#[inline(always)]
+fn get_env_value(env_key : &str, name : &str) -> Option<String> {
+    get_env_keys(env_key, is_nscfg_strict()).iter().find_map(|key| env::var(format!("{}{}", key, name)).ok())
+}
+
+
+/// Get name and value of a parametrized definition of name in environment variables, looking at crate-scoped key first.
+fn get_env_definition(env_key : &str, name : &str) -> Option<(String, String)> {
+    let definitions = get_env_definitions();
+
+    get_env_keys(env_key, is_nscfg_strict()).iter().find_map(|key| definitions.iter()
+        .find_map(|(env_name, value)| env_name.strip_prefix(key.as_str()).filter(|definition| get_alias_name(definition).eq(name)).map(|definition| (String::from(definition), value.clone()))))
+}
+
+/// Get environment variables with parameters in their name like `nscfg-simd(arch, feat)`. Read once for the macro being expanded.
+/// 
+/// Variables that aren't unicode are skipped.
+fn get_env_definitions() -> EnvDefinitions {
+
+    ENV_DEFINITIONS.with(|cache| {
+        // 1. Get definitions already read by expansion
+        if let Some(definitions) = cache.borrow().as_ref() {
+            return definitions.clone();
+        }
+
+        // 2. Read environment variables if not read yet
+        let definitions = Rc::new(env::vars_os().filter_map(|(env_name, value)| Some((env_name.into_string().ok()?, value.into_string().ok()?)))
+            .filter(|(env_name, _)| env_name.contains(ALIAS_PARAMS_START)).collect::<Vec<(String, String)>>());
+        *cache.borrow_mut() = Some(definitions.clone());
+        definitions
+    })
+
+}
+
+
+/// Get nscfg.toml and alias packs read for configuration.
+/// 
+/// Cargo doesn't know the macro reads them, so they must be tracked to rebuild the crate when they change.
+pub(crate) fn get_tracked_files() -> Vec<PathBuf> {
+    get_manifest().config.as_ref().map(|config| config.files.clone()).unwrap_or_default()
 }
 
 
 /// Returns True if cfg-attr is generated for documentation labels.
 #[inline(always)]
 pub(crate) fn if_docsrs_enabled() -> bool {
-    // 1. Get previous result from cache. 
-    match env::var(NSCFG_CARGO_CACHE) {
-        Ok(value) => {
-            value.eq("true")
+    get_manifest().docsrs
+}
+
+/// Get Cargo.toml of crate being compiled from cache. Read it if not cached.
+fn get_manifest() -> Rc<Manifest> {
+
+    let dir = env::var(CARGO_MANIFEST_DIR).unwrap_or_default();
+
+    MANIFEST.with(|cache| {
+        // 1. Get previous result from cache.
+        if let Some(manifest) = cache.borrow().as_ref() {
+            if manifest.dir.eq(&dir) {
+                return manifest.clone();
+            }
+        }
+
+        // 2. Read Cargo.toml if no result
+        let manifest = Rc::new(read_manifest(dir));
+        *cache.borrow_mut() = Some(manifest.clone());
+        manifest
+    })
+
+}
+
+/// Read Cargo.toml in directory given.
+fn read_manifest(dir : String) -> Manifest {
+
+    let file_path = Path::new(&dir).join(CARGO_MANIFEST_NAME);
+
+    match fs::read_to_string(&file_path){
+        Ok(content) => Manifest {
+            docsrs : content.contains(NSCFG_DOCRS_TAG),
+            config : read_nscfg_config(&file_path, &content),
+            crate_path : format!("::{}", find_nscfg_dependency_name(&content).unwrap_or(String::from(NSCFG_CRATE_NAME))),
+            dir,
         },
-        Err(_) => {
-            // 2. Read Cargo.toml if no result
-            let str_path =  format!("{}/{}", env::var(CARGO_MANIFEST_DIR).unwrap(), CARGO_MANIFEST_NAME);
-            let file_path = Path::new(&str_path);
-
-            match fs::read_to_string(file_path){
-                Ok(content) => {
-                    match content.find(NSCFG_DOCRS_TAG){
-                        Some(_) => { 
-                            env::set_var(NSCFG_CARGO_CACHE, "true");    // Cache result
-                            true
-                        },
-                        None => {
-                            env::set_var(NSCFG_CARGO_CACHE, "false");    // Cache result
-                            false
-                        },
-                    }
-                },
 
-                // Cargo.toml not found, return false.
-                Err(_) => {
-                    env::set_var(NSCFG_CARGO_CACHE, "false");
-                    false
-                },
+        // Cargo.toml not found, no configuration.
+        Err(_) => Manifest { dir, docsrs : false, config : Ok(NscfgConfig::default()), crate_path : format!("::{}", NSCFG_CRATE_NAME) },
+    }
+
+}
+
+/// Find the name nscfg is renamed to in dependencies, like `cfg = { package = "nscfg" }`.
+/// 
+/// Returns None if nscfg isn't renamed or if Cargo.toml can't be parsed.
+fn find_nscfg_dependency_name(content : &str) -> Option<String> {
+    parse_toml(content).ok()?.iter().find_map(|(path, value)| match (path.as_slice(), value) {
+        ([.., table, name, key], TomlValue::String(package)) if table.ends_with(DEPENDENCIES_TABLE_SUFFIX) && key.eq(DEPENDENCY_PACKAGE_KEY) && package.eq(NSCFG_CRATE_NAME) => Some(name.replace('-', "_")),
+        _ => None,
+    })
+}
+
+/// Get the path of nscfg crate used by the crate being compiled. `::nscfg` unless renamed in Cargo.toml dependencies.
+#[inline(always)]
+pub(crate) fn get_nscfg_crate_path() -> String {
+    get_manifest().crate_path.clone()
+}
+
+/// Read nscfg configuration of Cargo.toml content, nscfg.toml next to it and included alias packs.
+/// 
+/// Workspace configuration is inherited unless `inherit = false`. Precedence is nscfg.toml > package > workspace > included packs.
+/// 
+/// Error(s)
+/// Returns Err(String) with file path if a configuration is invalid or if packs define the same name.
+fn read_nscfg_config(file_path : &Path, content : &str) -> Result<NscfgConfig, String> {
+
+    // 1. Read package configuration
+    let entries = parse_toml(content).map_err(|err| format!("{} {}", file_path.display(), err))?;
+    let mut config = parse_nscfg_config(&entries, &NSCFG_METADATA_TABLE).map_err(|err| format!("{} {}", file_path.display(), err))?;
+    config.resolve_include(file_path);
+
+    // 2. Read nscfg.toml, which has priority over Cargo.toml
+    let local_path = file_path.with_file_name(NSCFG_LOCAL_FILE);
+    if local_path.exists() {
+        let mut local = read_nscfg_file(&local_path)?;
+        local.inherit = local.inherit.or(config.inherit);
+        local.inherit_from(config);
+        config = local;
+    }
+
+    // 3. Inherit workspace configuration
+    if config.inherit.unwrap_or(true) {
+        if let Some((workspace_path, workspace_entries)) = find_workspace_root(file_path, entries)? {
+            let mut workspace = parse_nscfg_config(&workspace_entries, &NSCFG_WORKSPACE_TABLE).map_err(|err| format!("{} {}", workspace_path.display(), err))?;
+            workspace.resolve_include(&workspace_path);
+            config.inherit_from(workspace);
+        }
+    }
+
+    // 4. Append included packs
+    let packs = read_nscfg_packs(&config.include)?;
+    config.inherit_from(packs);
+
+    Ok(config)
+
+}
+
+/// Read nscfg configuration file where keys are at root.
+/// 
+/// Error(s)
+/// Returns Err(String) with file path if file can't be read or is invalid.
+fn read_nscfg_file(file_path : &Path) -> Result<NscfgConfig, String> {
+
+    let content = fs::read_to_string(file_path).map_err(|err| format!("{} : {}", file_path.display(), err))?;
+    let entries = parse_toml(&content).map_err(|err| format!("{} {}", file_path.display(), err))?;
+    let mut config = parse_nscfg_config(&entries, &[]).map_err(|err| format!("{} {}", file_path.display(), err))?;
+    config.resolve_include(file_path);
+    config.files.push(fs::canonicalize(file_path).unwrap_or(file_path.to_path_buf()));
+
+    Ok(config)
+
+}
+
+/// Read aliases and predicates of included packs. Packs can include other packs. Other parameters of packs are ignored.
+/// 
+/// Error(s)
+/// Returns Err(String) if a pack is invalid or if 2 packs define the same alias or predicate.
+fn read_nscfg_packs(include : &[PathBuf]) -> Result<NscfgConfig, String> {
+
+    // Definitions with the pack defining them
+    let mut aliases : Vec<(String, String, PathBuf)> = Vec::new();
+    let mut predicates : Vec<(String, String, PathBuf)> = Vec::new();
+    let mut files : Vec<PathBuf> = Vec::new();
+
+    // 1. Read each pack once, following includes.
+    let mut visited : Vec<PathBuf> = Vec::new();
+    let mut to_read : Vec<PathBuf> = include.iter().rev().cloned().collect();
+
+    while let Some(pack_path) = to_read.pop() {
+        let canonical = fs::canonicalize(&pack_path).unwrap_or(pack_path.clone());
+        if visited.contains(&canonical) {
+            continue;
+        }
+        visited.push(canonical);
+
+        let pack = read_nscfg_file(&pack_path)?;
+        add_pack_definitions(&mut aliases, pack.aliases, &pack_path, "alias")?;
+        add_pack_definitions(&mut predicates, pack.predicates, &pack_path, "predicate")?;
+        files.extend(pack.files);
+        to_read.extend(pack.include.into_iter().rev());
+    }
+
+    // 2. Remove pack path
+    Ok(NscfgConfig {
+        aliases : aliases.into_iter().map(|(name, value, _)| (name, value)).collect(),
+        predicates : predicates.into_iter().map(|(name, value, _)| (name, value)).collect(),
+        files,
+        ..Default::default()
+    })
+
+}
+
+/// Add definitions of a pack, verifying that no other pack defines the same name.
+/// 
+/// Error(s)
+/// Returns Err(String) naming both packs if a definition is duplicated.
+fn add_pack_definitions(definitions : &mut Vec<(String, String, PathBuf)>, pack_definitions : Vec<(String, String)>, pack_path : &Path, kind : &str) -> Result<(), String> {
+
+    for (name, value) in pack_definitions {
+        match definitions.iter().find(|definition| definition.0.eq(&name)) {
+            Some(definition) => return Err(format!("{} : {} `{}` is already defined in {}.", pack_path.display(), kind, name, definition.2.display())),
+            None => definitions.push((name, value, pack_path.to_path_buf())),
+        }
+    }
+
+    Ok(())
+
+}
+
+/// Find the workspace root Cargo.toml of a package and returns its path and entries.
+/// 
+/// The package itself is the root if it has a [workspace] table. Otherwise `package.workspace` is used if set, else
+/// the nearest parent directory with a Cargo.toml containing a [workspace] table.
+/// 
+/// Error(s)
+/// Returns Err(String) if workspace Cargo.toml can't be read or is invalid.
+fn find_workspace_root(file_path : &Path, entries : TomlEntries) -> Result<Option<(PathBuf, TomlEntries)>, String> {
+
+    // 1. Package is the workspace root
+    if has_toml_table(&entries, &WORKSPACE_TABLE) {
+        return Ok(Some((file_path.to_path_buf(), entries)));
+    }
+
+    let dir = file_path.parent().unwrap_or(Path::new(""));
+
+    // 2. Workspace root explicitly given
+    if let Some(TomlValue::String(root)) = get_toml_value(&entries, &PACKAGE_WORKSPACE_KEY) {
+        let root_path = dir.join(root).join(CARGO_MANIFEST_NAME);
+        return match fs::read_to_string(&root_path) {
+            Ok(content) => Ok(Some((root_path.clone(), parse_workspace_manifest(&root_path, &content)?))),
+            Err(err) => Err(format!("{} : {}", root_path.display(), err)),
+        };
+    }
+
+    // 3. Search parent directories
+    for parent in dir.ancestors().skip(1) {
+        let root_path = parent.join(CARGO_MANIFEST_NAME);
+        if let Ok(content) = fs::read_to_string(&root_path) {
+            let root_entries = parse_workspace_manifest(&root_path, &content)?;
+            if has_toml_table(&root_entries, &WORKSPACE_TABLE) {
+                return Ok(Some((root_path, root_entries)));
             }
         }
     }
+
+    Ok(None)
+
+}
+
+/// Parse a Cargo.toml that may be the workspace root.
+/// 
+/// Manifests that can't be parsed are only an error if they declare nscfg workspace metadata. Otherwise they are
+/// kept with only their [workspace] table so an unrelated or unsupported manifest doesn't break configuration.
+/// 
+/// Error(s)
+/// Returns Err(String) if manifest declares nscfg workspace metadata and is invalid.
+fn parse_workspace_manifest(file_path : &Path, content : &str) -> Result<TomlEntries, String> {
+
+    match parse_toml(content) {
+        Ok(entries) => Ok(entries),
+        Err(err) => {
+            let is_workspace = content.lines().any(|line| line.trim_start().starts_with(WORKSPACE_HEADER));
+            if is_workspace && content.contains(NSCFG_WORKSPACE_TABLE[2]) {
+                Err(format!("{} {}", file_path.display(), err))
+            } else if is_workspace {
+                Ok(vec![(WORKSPACE_TABLE.iter().map(|key| String::from(*key)).collect(), TomlValue::Table(Vec::new()))])
+            } else {
+                Ok(Vec::new())
+            }
+        },
+    }
+
+}
+
+/// Parse nscfg configuration from table of TOML entries.
+/// 
+/// Error(s)
+/// Returns Err(String) if a value has the wrong type.
+pub(crate) fn parse_nscfg_config(entries : &TomlEntries, table : &[&str]) -> Result<NscfgConfig, String> {
+
+    let mut config = NscfgConfig::default();
+
+    for (key, value) in get_toml_table(entries, table) {
+        match (key[0].as_str(), key.len(), value) {
+            (METADATA_ALIASES_KEY, 2, TomlValue::String(alias)) => config.aliases.push((key[1].clone(), alias.clone())),
+            (METADATA_PREDICATES_KEY, 2, TomlValue::String(predicate)) => config.predicates.push((key[1].clone(), predicate.clone())),
+            (METADATA_AUTODOC_KEY, 1, TomlValue::Boolean(autodoc)) => config.autodoc = Some(*autodoc),
+            (METADATA_MODIFIER_BEHAVIOUR_KEY, 1, TomlValue::String(behaviour)) => config.release_modifier_behaviour = Some(behaviour.clone()),
+            (METADATA_STRICT_KEY, 1, TomlValue::Boolean(strict)) => config.strict = Some(*strict),
+            (METADATA_MAX_ALIAS_DEPTH_KEY, 1, TomlValue::Other(depth)) if depth.parse::<usize>().is_ok() => config.max_alias_depth = depth.parse().ok(),
+            (METADATA_INHERIT_KEY, 1, TomlValue::Boolean(inherit)) => config.inherit = Some(*inherit),
+            (METADATA_TARGETS_KEY, 1, TomlValue::Array(targets)) => {
+                let mut triples : Vec<String> = Vec::new();
+                for target in targets {
+                    match target {
+                        TomlValue::String(target) => triples.push(target.clone()),
+                        _ => return Err(format!(": `{}` must be an array of strings.", METADATA_TARGETS_KEY)),
+                    }
+                }
+                config.targets = Some(triples);
+            },
+            (METADATA_INCLUDE_KEY, 1, TomlValue::Array(packs)) => {
+                for pack in packs {
+                    match pack {
+                        TomlValue::String(pack) => config.include.push(PathBuf::from(pack)),
+                        _ => return Err(format!(": `{}` must be an array of strings.", METADATA_INCLUDE_KEY)),
+                    }
+                }
+            },
+            (METADATA_ALIASES_KEY, 1, _) | (METADATA_PREDICATES_KEY, 1, _) => return Err(format!(": `{}` must be a table.", key.join("."))),
+            (METADATA_ALIASES_KEY, _, _) | (METADATA_PREDICATES_KEY, _, _) => return Err(format!(": `{}` must be a string.", key.join("."))),
+            (METADATA_AUTODOC_KEY, _, _) | (METADATA_STRICT_KEY, _, _) | (METADATA_INHERIT_KEY, _, _) => return Err(format!(": `{}` must be a boolean.", key.join("."))),
+            (METADATA_MODIFIER_BEHAVIOUR_KEY, _, _) => return Err(format!(": `{}` must be a string.", key.join("."))),
+            (METADATA_MAX_ALIAS_DEPTH_KEY, _, _) => return Err(format!(": `{}` must be a positive integer.", key.join("."))),
+            (METADATA_INCLUDE_KEY, _, _) | (METADATA_TARGETS_KEY, _, _) => return Err(format!(": `{}` must be an array of strings.", key.join("."))),
+            _ => {},    // Unknown keys are ignored
+        }
+    }
+
+    Ok(config)
+
+}
+
+/// Get custom aliases and predicates from Cargo.toml.
+/// 
+/// Error(s)
+/// Returns Err([NSCFGError::ConfigurationError]) if Cargo.toml configuration is invalid.
+#[inline(always)]
+fn get_config() -> Result<Rc<Manifest>, NSCFGError> {
+    let manifest = get_manifest();
+
+    match &manifest.config {
+        Ok(_) => Ok(manifest),
+        Err(err) => Err(NSCFGError::ConfigurationError(err.clone())),
+    }
 }
 
 
@@ -165,53 +674,225 @@ pub fn get_nscfg_predicate(tokens : &str) -> Result<String, NSCFGError> {
 
     // 1. Extract label and predicate from tokens
     match tokens.find(":") {
-        Some(position) => {
-            let label = tokens[0..position].trim();
-            let cfg_opt = tokens[position + 1..].trim();
-
-            // 2. Try to match environment variable to see if predicate was defined in config.toml.
-            match env::var(format!("{}{}", ENV_KEY_PREDICATE, cfg_opt)) {
-                Ok(cfg_value) => Ok(String::from(cfg_value.replace(PREDICATE_PLACEHOLDER, label))),
-                Err(_) =>  {
-                    // 3. Find predefined predicates
-                    match PREDICATES.iter().find(|p| p.0.eq(cfg_opt)){
-                        // Predicate found, return value
-                        Some(pred) =>  Ok(String::from(pred.1.replace(PREDICATE_PLACEHOLDER, label))),
-
-                        // Not found, raise error.
-                        None => Err(NSCFGError::InvalidConfigurationPredicate(String::from(cfg_opt))),
-                    }
-                },
-            }
-        },
+        Some(position) => get_nscfg_leaf_predicate(tokens[0..position].trim(), tokens[position + 1..].trim()),
 
         // Should never happen but good to have in hand
-        None => Err(NSCFGError::InvalidConfigurationPredicate(String::from(tokens))),
+        None => Err(NSCFGError::InvalidConfigurationPredicate(String::from(tokens), None)),
     } 
 
 }
 
+/// Generate configuration predicate from leaf value and predicate. Value is written as is in predicate.
+/// 
+/// Error(s)
+/// Returns Err([SyntaxParseError::InvalidConfigurationPredicate]) if predicate not defined.
+pub fn get_nscfg_leaf_predicate(label : &str, cfg_opt : &str) -> Result<String, NSCFGError> {
+
+    // 1. Try to match environment variable to see if predicate was defined in config.toml.
+    match get_env_value(ENV_KEY_PREDICATE, cfg_opt) {
+        Some(cfg_value) => Ok(cfg_value.replace(PREDICATE_PLACEHOLDER, label)),
+        None =>  {
+            // 2. Find predicates in Cargo.toml. Invalid configuration is only reported if predicate isn't built-in.
+            let manifest = get_manifest();
+            if let Some(pred) = manifest.config.as_ref().ok().and_then(|config| config.predicates.iter().find(|p| p.0.eq(cfg_opt))) {
+                return Ok(pred.1.replace(PREDICATE_PLACEHOLDER, label));
+            }
+
+            // 3. Find predefined predicates
+            match PREDICATES.iter().find(|p| p.0.eq(cfg_opt)){
+                // Predicate found, return value
+                Some(pred) =>  Ok(pred.1.replace(PREDICATE_PLACEHOLDER, label)),
+
+                // Not found, raise error with nearest predicate.
+                None => {
+                    let manifest = get_config()?;
+                    let config = manifest.config.as_ref().unwrap();
+                    Err(NSCFGError::InvalidConfigurationPredicate(String::from(cfg_opt), get_suggestion(cfg_opt, ENV_KEY_PREDICATE, &config.predicates, &PREDICATES, &[])))
+                },
+            }
+        },
+    }
+
+}
+
 
-/// Parse label to generate alias content.
+/// Parse label to generate alias content, substituting `{param}` placeholders of a parametrized alias with args.
+/// 
+/// Returns the content with where alias is defined.
 /// 
 /// Error(s)
-/// Returns Err([TargetCfgError::AliasNotFound]) if alias not defined.
+/// Returns Err([NSCFGError::AliasNotFound]) if alias not defined.
+/// Returns Err([NSCFGError::AliasArityMismatch]) if args count isn't the count of alias parameters.
 #[inline(always)]
-pub fn get_nscfg_alias(label : &str) -> Result<String, NSCFGError> {
+pub fn get_nscfg_alias(label : &str, args : &[String]) -> Result<(String, DefinitionSource), NSCFGError> {
 
-    // 1. Try to match environment variable to see if it was defined in config.toml.
-    match env::var(format!("{}{}", ENV_KEY_ALIAS, label)) {
-        Ok(alias) => Ok(alias.clone()),     
-        Err(_e) => {
-            // 2. Find predefined alias
-            match ALIASES.iter().find(|a| a.0.eq(label)){
-                // Alias found, return value
-                Some(alias) => Ok(String::from(alias.1)),
-
-                // Not found, raise error.
-                None => Err(NSCFGError::AliasNotFound(String::from(label))),
+    match find_nscfg_alias(label)? {
+        // Alias found, substitute parameters and return value
+        Some((params, alias, source)) => {
+            if params.len() != args.len() {
+                return Err(NSCFGError::AliasArityMismatch(String::from(label), params.len(), args.len()));
             }
+
+            Ok((substitute_alias_params(&alias, &params, args), source))
         },
+
+        // Not found, raise error with nearest alias.
+        None => {
+            let manifest = get_config()?;
+            let config = manifest.config.as_ref().unwrap();
+            let source = SOURCE_ALIASES.with(|aliases| aliases.borrow().clone());
+            Err(NSCFGError::AliasNotFound(String::from(label), get_suggestion(label, ENV_KEY_ALIAS, &config.aliases, &ALIASES, &source)))
+        },
+    }
+
+}
+
+/// Find alias parameters, content and where it is defined.
+/// 
+/// Parametrized aliases are defined with their parameters after name, like `simd(arch, feat)`, and found with name only.
+/// Environment variables have priority over configuration, then built-in aliases, then aliases defined with cfg_alias!.
+/// 
+/// Error(s)
+/// Returns Err([NSCFGError::ConfigurationError]) if Cargo.toml configuration is invalid and alias isn't defined elsewhere.
+pub(crate) fn find_nscfg_alias(label : &str) -> Result<Option<(Vec<String>, String, DefinitionSource)>, NSCFGError> {
+
+    // 1. Try to match environment variable to see if it was defined in config.toml.
+    if let Some(alias) = get_env_value(ENV_KEY_ALIAS, label) {
+        return Ok(Some((Vec::new(), alias, DefinitionSource::Env)));
+    }
+    if let Some((name, alias)) = get_env_definition(ENV_KEY_ALIAS, label) {
+        return Ok(Some((get_alias_params(&name), alias, DefinitionSource::Env)));
+    }
+
+    // 2. Find alias in Cargo.toml. Invalid configuration is only reported if alias isn't defined elsewhere.
+    let manifest = get_manifest();
+    if let Some(alias) = manifest.config.as_ref().ok().and_then(|config| config.aliases.iter().find(|a| get_alias_name(&a.0).eq(label))) {
+        return Ok(Some((get_alias_params(&alias.0), alias.1.clone(), DefinitionSource::Config)));
+    }
+
+    // 3. Find predefined alias
+    if let Some(alias) = ALIASES.iter().find(|a| a.0.eq(label)) {
+        return Ok(Some((Vec::new(), String::from(alias.1), DefinitionSource::BuiltIn)));
+    }
+
+    // 4. Find alias defined in source
+    if let Some(alias) = SOURCE_ALIASES.with(|aliases| aliases.borrow().iter().find(|a| get_alias_name(&a.0).eq(label)).map(|a| (get_alias_params(&a.0), a.1.clone(), DefinitionSource::Source))) {
+        return Ok(Some(alias));
     }
 
-}
\ No newline at end of file
+    // 5. Alias may be defined in invalid configuration
+    get_config().map(|_| None)
+
+}
+
+/// Get the name of an alias definition without its parameters (`simd` for `simd(arch, feat)`).
+#[inline(always)]
+fn get_alias_name(definition : &str) -> &str {
+    definition.split(ALIAS_PARAMS_START).next().unwrap_or_default().trim()
+}
+
+/// Get the parameters of an alias definition (`[arch, feat]` for `simd(arch, feat)`). Empty if alias isn't parametrized.
+fn get_alias_params(definition : &str) -> Vec<String> {
+    match definition.split_once(ALIAS_PARAMS_START) {
+        Some((_, params)) => params.trim_end().trim_end_matches(ALIAS_PARAMS_END).split(ALIAS_PARAMS_SEPARATOR)
+            .map(|param| String::from(param.trim())).filter(|param| !param.is_empty()).collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Replace each `{param}` placeholder of alias with its argument. Spaces inside braces are ignored (`{ arch }`).
+fn substitute_alias_params(alias : &str, params : &[String], args : &[String]) -> String {
+
+    let mut content = String::new();
+    let mut rest = alias;
+
+    while let Some(start) = rest.find(ALIAS_PLACEHOLDER_START) {
+        content.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        // Unknown placeholders are kept as written
+        match rest.find(ALIAS_PLACEHOLDER_END).and_then(|end| params.iter().position(|param| param.eq(rest[1..end].trim())).map(|pos| (end, pos))) {
+            Some((end, pos)) => {
+                content.push_str(&args[pos]);
+                rest = &rest[end + 1..];
+            },
+            None => {
+                content.push(ALIAS_PLACEHOLDER_START);
+                rest = &rest[1..];
+            },
+        }
+    }
+    content.push_str(rest);
+
+    content
+
+}
+
+/// Set aliases defined with cfg_alias! for the macro being expanded.
+/// 
+/// Each expansion starts here, so environment definitions are read again by the new expansion.
+#[inline(always)]
+pub(crate) fn set_source_aliases(aliases : Vec<(String, String)>) {
+    SOURCE_ALIASES.with(|source| *source.borrow_mut() = aliases);
+    ENV_DEFINITIONS.with(|cache| *cache.borrow_mut() = None);
+}
+
+
+/// Get the nearest name of label from environment variables starting with env_key, from Cargo.toml configuration, from built-in names
+/// and from names defined in source.
+/// 
+/// Returns None if no name is near enough. When at same distance, priority is environment variables, configuration, built-in then source.
+pub(crate) fn get_suggestion(label : &str, env_key : &str, config : &[(String, String)], built_in : &[(&str, &str)], source : &[(String, String)]) -> Option<(String, DefinitionSource)> {
+
+    // 1. Maximum distance accepted is a third of label length (minimum 1).
+    let max_distance = usize::max(1, label.chars().count() / 3);
+
+    // 2. Candidates from environment variables, then configuration, then built-in.
+    let env_keys = get_env_keys(env_key, is_nscfg_strict());
+    let candidates = env::vars_os().filter_map(|(key, _)| key.into_string().ok())     // Keys that aren't unicode can't be a name
+        .filter_map(|key| env_keys.iter().find_map(|env_key| key.strip_prefix(env_key.as_str())).map(|name| (String::from(name), DefinitionSource::Env)))
+        .chain(config.iter().map(|(name, _)| (name.clone(), DefinitionSource::Config)))
+        .chain(built_in.iter().map(|(name, _)| (String::from(*name), DefinitionSource::BuiltIn)))
+        .chain(source.iter().map(|(name, _)| (name.clone(), DefinitionSource::Source)));
+
+    // 3. Keep nearest candidate
+    let mut suggestion : Option<(usize, (String, DefinitionSource))> = None;
+    for candidate in candidates {
+        let distance = get_edit_distance(label, get_alias_name(&candidate.0));
+
+        let is_nearer = match &suggestion {
+            Some((nearest, _)) => distance < *nearest,
+            None => true,
+        };
+
+        if distance <= max_distance && is_nearer {
+            suggestion = Some((distance, candidate));
+        }
+    }
+
+    suggestion.map(|(_, candidate)| candidate)
+
+}
+
+/// Get the Levenshtein distance between 2 strings (number of insertions, deletions and substitutions).
+pub(crate) fn get_edit_distance(a : &str, b : &str) -> usize {
+
+    let b : Vec<char> = b.chars().collect();
+
+    // Distances of previous row
+    let mut previous : Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + if ca == *cb { 0 } else { 1 };
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        previous = current;
+    }
+
+    previous[b.len()]
+
+}
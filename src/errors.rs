@@ -1,3 +1,7 @@
+use proc_macro::{TokenStream, TokenTree, Span, Ident, Punct, Spacing, Group, Delimiter, Literal};
+
+use crate::config::{DefinitionSource, ENV_KEY_ALIAS, ENV_KEY_PREDICATE, get_env_key_hint, MAX_ALIAS_DEPTH_KEY, RANGE_DOMAINS};
+use crate::options::TARGET_OPTIONS;
 use crate::arm::{ARM_SEPARATOR, CONTENT_SEPARATOR_0, CONTENT_SEPARATOR_1, WILDCARD_ARM, MODIFIER_ACTIVATE, MODIFIER_DEACTIVATE, MODIFIER_PANIC};
 
 /* 
@@ -35,11 +39,11 @@ pub enum NSCFGError {
     /// Invalid character used
     InvalidCharacter(String),
 
-    /// Alias written is not found
-    AliasNotFound(String),
+    /// Alias written is not found, with nearest alias and where it is defined.
+    AliasNotFound(String, Option<(String, DefinitionSource)>),
 
-    /// Invalid configuration predicate
-    InvalidConfigurationPredicate(String),
+    /// Invalid configuration predicate, with nearest predicate and where it is defined.
+    InvalidConfigurationPredicate(String, Option<(String, DefinitionSource)>),
 
     /// Happens when having an empty arm.
     EmptyArm,
@@ -56,11 +60,17 @@ pub enum NSCFGError {
     /// Happens when wildcard arm is not set for match_cfg!.
     WildcardArmMissing,
 
-    /// Happens when wildcard arm is set when using single_cfg!
-    WildcardArmOnTarget,
+    /// Happens when wildcard arm is set when using target_cfg! or stmt_cfg!, with the macro name.
+    WildcardArmOnTarget(String),
+
+    /// Happens when trying to use target_cfg! inside a function, with the token starting a statement instead of an item.
+    TargetInFunction(String),
+
+    /// Happens when cfg_consts! arm content isn't a const, with the token starting it.
+    ConstExpected(String),
 
-    /// Happens when trying to use target_cfg! inside a function.
-    TargetInFunction,
+    /// Happens when a cfg_consts! arm doesn't define a const defined by other arms, with arm and const name.
+    ConstMissing(String, String),
 
     /// Happens when legacy syntax is incorrect
     LegacySyntaxError,
@@ -83,24 +93,80 @@ pub enum NSCFGError {
 
     /// Happens when using modifier `-` on wildcard arm of match_cfg!.
     MatchDeactivatedWildArm,
+
+    /// Happens when nscfg configuration in Cargo.toml can't be read.
+    ConfigurationError(String),
+
+    /// Happens when an alias refers to itself, directly or through other aliases.
+    AliasCycle(Vec<String>),
+
+    /// Happens when aliases refer to each other deeper than maximum depth.
+    AliasDepthExceeded(usize, Vec<String>),
+
+    /// Happens when alias content from environment or configuration can't be tokenized, with the alias.
+    AliasContentError(String),
+
+    /// Happens when cfg_alias! definition isn't `name = expression;`.
+    AliasDefinitionError,
+
+    /// Happens when cfg_alias! option isn't `crate = path`.
+    AliasOptionError,
+
+    /// Happens when cfg_alias! defines an alias already defined elsewhere.
+    AliasAlreadyDefined(String, DefinitionSource),
+
+    /// Happens when a parametrized alias is written with a different count of arguments than its parameters, with parameters and arguments count.
+    AliasArityMismatch(String, usize, usize),
+
+    /// Happens when no arm of an exhaustive target_cfg! matches, with arms and configuration not covered.
+    NonExhaustiveArms(Vec<String>, String),
+
+    /// Happens when arms of match_cfg! or exhaustive target_cfg! don't cover supported targets, with targets not covered.
+    UncoveredTargets(Vec<String>),
+
+    /// Happens when two arms of a disjoint target_cfg! match, with both arms.
+    OverlappingArms(String, String),
+
+    /// Happens when target_cfg! is given an option that doesn't exist.
+    UnknownOption(String),
+
+    /// Happens when a range leaf like `>=32:pw` is used with a predicate without known domain, with the predicate.
+    RangeNotSupported(String),
+
+    /// Happens when a range leaf bound isn't a number of its predicate domain or when no value is in range, with the bounds accepted.
+    RangeOutOfDomain(Vec<&'static str>),
 }
 
 /// Error message implementation.
 impl NSCFGError {
+    /// Locate error at a single token span.
+    pub fn at(self, tokens : &str, span : Span) -> NSCFGSpanError {
+        self.between(tokens, span, span)
+    }
+
+    /// Locate error from the first token span to the last token span.
+    pub fn between(self, tokens : &str, start : Span, end : Span) -> NSCFGSpanError {
+        NSCFGSpanError { error : self, tokens : String::from(tokens), start, end }
+    }
+
     pub fn message(&self, tokens : &str) -> String {
         match self {
             NSCFGError::MissingOperator => format!("Operator `&` or '|' missing for `{:?}`. Target must not contain space.", tokens),
             NSCFGError::EmptyNode =>  format!("Empty node generated from attributes. Are you missing a statement between separator?"),
             NSCFGError::InvalidCharacter(c) => format!("Invalid character `{}` for `{:?}`.", c, tokens),
-            NSCFGError::AliasNotFound(alias) => format!("Alias `{}` has no match! Is it added in config.toml as `target_cfg-{}`?", alias, alias),
-            NSCFGError::InvalidConfigurationPredicate(cfg_prd) => format!("Configuration predicate `{}` has no match! Is it added in config.toml as `target_cfg_predicate-{}`?", cfg_prd, cfg_prd),
+            NSCFGError::AliasNotFound(alias, Some((near, source))) => format!("Alias `{}` not found, did you mean `{}` ({} alias)? Or is it defined with cfg_alias! before being used?", alias, near, source.name()),
+            NSCFGError::AliasNotFound(alias, None) => format!("Alias `{}` has no match! Is it added in config.toml as `{}{}` or defined with cfg_alias! before being used?", alias, get_env_key_hint(ENV_KEY_ALIAS), alias),
+            NSCFGError::InvalidConfigurationPredicate(cfg_prd, Some((near, source))) => format!("Configuration predicate `{}` not found, did you mean `{}` ({} predicate)?", cfg_prd, near, source.name()),
+            NSCFGError::InvalidConfigurationPredicate(cfg_prd, None) => format!("Configuration predicate `{}` has no match! Is it added in config.toml as `{}{}`?", cfg_prd, get_env_key_hint(ENV_KEY_PREDICATE), cfg_prd),
             NSCFGError::EmptyArm => format!("Empty arm with no attributes detected!"),
             NSCFGError::WildcardArmNotLast => format!("Wildcard branch `_` must ALWAYS be the last branch."),
             NSCFGError::ArmSeparatorMissing => format!("Arm syntax incorrect. Are you missing a separator `{}` between arms?", ARM_SEPARATOR),
             NSCFGError::ContentSeparatorError => format!("Arm syntax incorrect. Is your arm separator `{}{}` syntax Ok?", CONTENT_SEPARATOR_0, CONTENT_SEPARATOR_1),
             NSCFGError::WildcardArmMissing => format!("Ensure that all possible cases are being handled by adding a match arm with a `{}` wildcard pattern.", WILDCARD_ARM),
-            NSCFGError::WildcardArmOnTarget => format!("target_cfg! macro cannot have a `{}` wildcard pattern.", WILDCARD_ARM),
-            NSCFGError::TargetInFunction => format!("target_cfg! macro cannot be used inside a function. Use match_cfg! instead."),
+            NSCFGError::WildcardArmOnTarget(macro_name) => format!("{}! macro cannot have a `{}` wildcard pattern.", macro_name, WILDCARD_ARM),
+            NSCFGError::TargetInFunction(token) => format!("Arm content starting with `{}` isn't an item! target_cfg! macro cannot be used inside a function. Use match_cfg! instead.", token),
+            NSCFGError::ConstExpected(token) => format!("Arm content starting with `{}` isn't a const! cfg_consts! arms can only contain const items.", token),
+            NSCFGError::ConstMissing(arm, name) => format!("Arm `{}` doesn't define const `{}`! Each arm of cfg_consts! must define the same consts.", arm, name),
             NSCFGError::LegacySyntaxError => format!("Legacy syntax error in `{}`.", tokens),
             NSCFGError::MixedSyntaxError => format!("Legacy syntax and simplified syntax can't be mixed on same arm!"),
             NSCFGError::ContentSeparatorMissing => format!("Arm content separator `{}{}` missing!", CONTENT_SEPARATOR_0, CONTENT_SEPARATOR_1),
@@ -108,6 +174,92 @@ impl NSCFGError {
             NSCFGError::ModifierPanicRelease => format!("Arm modifiers `{}` and `{}` will panic during release compilation by default! This behaviour can be changed. See https://github.com/NickelAngeStudio/nscfg/wiki/Syntax#six-modifiers", MODIFIER_ACTIVATE, MODIFIER_DEACTIVATE),
             NSCFGError::MatchModifierMoreThanOneActivate => format!("match_cfg! cannot have more than one `{}` modifier!", MODIFIER_ACTIVATE),
             NSCFGError::MatchDeactivatedWildArm => format!("match_cfg! cannot deactivate wildcard arm with `{}` modifier!", MODIFIER_DEACTIVATE),
+            NSCFGError::ConfigurationError(err) => format!("nscfg configuration error in {}", err),
+            NSCFGError::AliasCycle(cycle) => format!("Alias cycle detected : `{}`.", cycle.join(" -> ")),
+            NSCFGError::AliasDepthExceeded(depth, chain) => format!("Alias expansion `{}` exceeds maximum depth of {}! Maximum depth can be changed with `{}`.", chain.join(" -> "), depth, MAX_ALIAS_DEPTH_KEY),
+            NSCFGError::AliasContentError(alias) => format!("Alias `{}` content `{}` can't be tokenized! Verify its quotes and delimiters.", alias, tokens),
+            NSCFGError::AliasDefinitionError => format!("cfg_alias! syntax incorrect for `{}`. Aliases must be defined as `name = expression;` or `name(param, ...) = expression;`.", tokens),
+            NSCFGError::AliasOptionError => format!("cfg_alias! option `{}` incorrect! Only `crate = path` can be given, with the path of nscfg when re-exported.", tokens),
+            NSCFGError::AliasAlreadyDefined(alias, source) => format!("Alias `{}` is already defined as {} alias!", alias, source.name()),
+            NSCFGError::AliasArityMismatch(alias, params, args) => format!("Alias `{}` expects {} argument(s), {} given!", alias, params, args),
+            NSCFGError::NonExhaustiveArms(arms, uncovered) => format!("No arm of exhaustive target_cfg! matches this configuration! Arms `{}` don't cover `{}`.", arms.join("`, `"), uncovered),
+            NSCFGError::UncoveredTargets(targets) => format!("Arms don't cover supported targets `{}`! Add arms for them or a `{}` wildcard arm.", targets.join("`, `"), WILDCARD_ARM),
+            NSCFGError::OverlappingArms(arm_a, arm_b) => format!("Arms `{}` and `{}` of disjoint target_cfg! both match this configuration!", arm_a, arm_b),
+            NSCFGError::UnknownOption(option) => format!("Unknown target_cfg! option `{}`. Available options are `{}`.", option, TARGET_OPTIONS.join("`, `")),
+            NSCFGError::RangeNotSupported(predicate) => format!("Range `{}` can't be used with predicate `{}`! Only `{}` predicates have a known domain.", tokens, predicate, RANGE_DOMAINS.map(|(key, _)| key).join("`, `")),
+            NSCFGError::RangeOutOfDomain(domain) => format!("Range `{}` is out of domain! Bound must be one of `{}` and at least one value must be in range.", tokens, domain.join("`, `")),
+        }
+    }
+}
+
+/// Error with the location of the tokens that caused it.
+pub struct NSCFGSpanError {
+    pub error : NSCFGError,     // Error raised
+    pub tokens : String,        // Tokens written in message
+    pub start : Span,           // Span of first token
+    pub end : Span,             // Span of last token
+}
+
+impl NSCFGSpanError {
+    /// Generate a `compile_error!` tokenstream for each error.
+    pub fn to_compile_errors(errors : &[NSCFGSpanError]) -> TokenStream {
+        errors.iter().map(|err| err.to_compile_error()).collect()
+    }
+
+    /// Generate a `compile_error!` for each error usable in expression position.
+    /// 
+    /// Many errors are written in a block `{ compile_error!{..}; compile_error!{..} }` since an expression
+    /// can only expand to a single `compile_error!`.
+    pub fn to_compile_errors_expr(errors : &[NSCFGSpanError]) -> TokenStream {
+        if errors.len() <= 1 {
+            return Self::to_compile_errors(errors);
         }
+
+        let mut block = TokenStream::new();
+        for (i, err) in errors.iter().enumerate() {
+            if i > 0 {
+                block.extend(TokenStream::from(TokenTree::from(Punct::new(';', Spacing::Alone))));
+            }
+            block.extend(err.to_compile_error());
+        }
+
+        TokenStream::from(TokenTree::from(Group::new(Delimiter::Brace, block)))
     }
-}
\ No newline at end of file
+
+    /// Generate a `compile_error!` tokenstream underlining the tokens that caused the error.
+    /// 
+    /// Since Span::join() isn't stable, the first token gets the start span and the last token gets the end span
+    /// so the compiler underline everything between.
+    pub fn to_compile_error(&self) -> TokenStream {
+        let mut punct = Punct::new('!', Spacing::Alone);
+        punct.set_span(self.start);
+
+        let mut message = Literal::string(&self.error.message(&self.tokens));
+        message.set_span(self.end);
+
+        let mut group = Group::new(Delimiter::Brace, TokenStream::from(TokenTree::from(message)));
+        group.set_span(self.end);
+
+        TokenStream::from_iter([
+            TokenTree::from(Ident::new("compile_error", self.start)),
+            TokenTree::from(punct),
+            TokenTree::from(group),
+        ])
+    }
+}
+
+
+/// Possible nscfg warnings.
+pub enum NSCFGWarning {
+    /// Happens when an arm meaning changed with operator precedence. Contains arm, previous and current predicates.
+    PrecedenceChanged(String, String, String),
+}
+
+/// Warning message implementation.
+impl NSCFGWarning {
+    pub fn message(&self) -> String {
+        match self {
+            NSCFGWarning::PrecedenceChanged(arm, previous, current) => format!("Arm `{}` changed meaning since `!` now binds tighter than `&` which binds tighter than `|`. It was `{}` and is now `{}`. Add parenthesis or remove double `!` to remove this warning.", arm, previous, current),
+        }
+    }
+}
@@ -0,0 +1,389 @@
+// Recognize the boundaries of items written in target_cfg! arms and statements written in stmt_cfg! arms so each one gets its own #[cfg].
+//
+// Items and statements aren't parsed, only their end is found from the tokens that start them.
+// See https://doc.rust-lang.org/reference/items.html and https://doc.rust-lang.org/reference/statements.html
+
+use proc_macro::{TokenStream, TokenTree, Delimiter, Spacing, Punct, Span, Group};
+
+/// Item terminator
+const ITEM_TERMINATOR : char = ';';
+
+/// Macro invocation symbol
+const MACRO_BANG : char = '!';
+
+/// Attribute symbol
+const ATTRIBUTE_HASH : char = '#';
+
+/// Keywords starting an item after qualifiers. `macro_rules!` is recognized as a macro invocation.
+const ITEM_KEYWORDS : [&str; 12] = ["fn", "struct", "enum", "union", "trait", "impl", "mod", "const", "static", "use", "type", "extern"];
+
+/// Macros that only expand to expressions or statements and thus can't be items.
+const STATEMENT_MACROS : [&str; 19] = ["print", "println", "eprint", "eprintln", "format", "panic", "assert", "assert_eq", "assert_ne",
+    "debug_assert", "debug_assert_eq", "debug_assert_ne", "dbg", "vec", "write", "writeln", "todo", "unimplemented", "unreachable"];
+
+/// Keywords starting an expression statement that ends with its braces.
+const BLOCK_KEYWORDS : [&str; 6] = ["if", "match", "while", "for", "loop", "unsafe"];
+
+/// Keyword continuing an `if` expression.
+const ELSE_KEYWORD : &str = "else";
+
+/// Keyword starting a const item.
+const CONST_KEYWORD : &str = "const";
+
+/// Keyword starting a let statement.
+const LET_KEYWORD : &str = "let";
+
+/// Keyword starting a for loop pattern.
+const FOR_KEYWORD : &str = "for";
+
+/// Keyword ending a for loop pattern.
+const IN_KEYWORD : &str = "in";
+
+/// Symbol ending the pattern of `if let` and `while let`.
+const PATTERN_ASSIGN : char = '=';
+
+/// Qualifiers that can be written before the item keyword.
+const ITEM_QUALIFIERS : [&str; 5] = ["unsafe", "async", "default", "auto", "safe"];
+
+/// Kind of item, deciding how it ends.
+enum ItemKind {
+    /// `const`, `static`, `use`, `type` and `extern crate` end at `;` since initializers and `use a::{b, c};` contain braces.
+    Terminated,
+
+    /// `extern "C" { }` ends with its braces.
+    ExternBlock,
+
+    /// Macro invocation ends with its braces or at `;`. Position is the one of its group.
+    Macro(usize),
+
+    /// Functions, structs, enums, traits, impl, mod, ... end with their braces at angle depth 0 or at `;`.
+    Body,
+}
+
+/// Split tokenstream in different [item](https://doc.rust-lang.org/reference/items.html) vector tokenstream.
+///
+/// Each item keeps its attributes. A macro invocation without braces or an item ending at `;` missing its last `;` gets one.
+pub(crate) fn split_items(stream : TokenStream) -> Vec<TokenStream> {
+
+    let tokens : Vec<TokenTree> = stream.into_iter().collect();
+    let mut items : Vec<TokenStream> = Vec::new();
+    let mut start = 0;
+
+    while start < tokens.len() {
+        let end = item_end(&tokens, start);
+        let mut item : TokenStream = tokens[start..end].iter().cloned().collect();
+
+        // Macros like `compile_error!("...")` and items like `const A : u32 = 1` are often written without `;` in arms.
+        let is_terminated_kind = matches!(item_kind(&tokens, skip_visibility(&tokens, skip_attributes(&tokens, start))), ItemKind::Terminated);
+        if end == tokens.len() && !is_punct(tokens.last(), ITEM_TERMINATOR) && (is_terminated_kind || is_unterminated_macro(&tokens[start..end])) {
+            item.extend(TokenStream::from(TokenTree::from(Punct::new(ITEM_TERMINATOR, Spacing::Alone))));
+        }
+
+        items.push(item);
+        start = end;
+    }
+
+    items
+
+}
+
+/// Split tokenstream in different [statement](https://doc.rust-lang.org/reference/statements.html) vector tokenstream.
+///
+/// Items and `let` statements are kept as written so what they declare is visible to following code. Other statements
+/// are put in braces since attributes can't be written on expressions like `a = 5;`. Statements missing their last `;` get one.
+pub(crate) fn split_statements(stream : TokenStream) -> Vec<TokenStream> {
+
+    let tokens : Vec<TokenTree> = stream.into_iter().collect();
+    let mut statements : Vec<TokenStream> = Vec::new();
+    let mut start = 0;
+
+    while start < tokens.len() {
+        // 1. Find where statement ends
+        let (position, is_item) = item_start(&tokens, start);
+        let end = if is_item { item_end(&tokens, start) } else { statement_end(&tokens, start) };
+        let mut statement : TokenStream = tokens[start..end].iter().cloned().collect();
+
+        // 2. Add missing `;`. Items only miss it when they are macros.
+        if !is_punct(tokens.get(end - 1), ITEM_TERMINATOR) && (!is_item || is_unterminated_macro(&tokens[start..end])) {
+            statement.extend(TokenStream::from(TokenTree::from(Punct::new(ITEM_TERMINATOR, Spacing::Alone))));
+        }
+
+        // 3. Put expression statements in braces
+        if is_item || matches!(tokens.get(position), Some(TokenTree::Ident(ident)) if ident.to_string().eq(LET_KEYWORD)) {
+            statements.push(statement);
+        } else {
+            statements.push(TokenStream::from(TokenTree::from(Group::new(Delimiter::Brace, statement))));
+        }
+
+        start = end;
+    }
+
+    statements
+
+}
+
+/// Find the first token of stream that starts a statement or an expression instead of an item.
+/// 
+/// Returns the token and its span, or None if stream can be items.
+pub(crate) fn find_statement(stream : TokenStream) -> Option<(String, Span)> {
+
+    let tokens : Vec<TokenTree> = stream.into_iter().collect();
+    let mut start = 0;
+
+    while start < tokens.len() {
+        let (position, is_item) = item_start(&tokens, start);
+
+        if !is_item {
+            let token = &tokens[position];
+            return Some((token.to_string(), token.span()));
+        }
+
+        start = item_end(&tokens, start);
+    }
+
+    None
+
+}
+
+/// Get the name of a const item `const NAME : Type = value;`.
+///
+/// Error(s)
+/// Returns Err with the token starting item and its span if item isn't a const.
+pub(crate) fn const_name(item : TokenStream) -> Result<String, (String, Span)> {
+
+    let tokens : Vec<TokenTree> = item.into_iter().collect();
+    let position = skip_visibility(&tokens, skip_attributes(&tokens, 0));
+
+    match (tokens.get(position), tokens.get(position + 1)) {
+        (Some(TokenTree::Ident(keyword)), Some(TokenTree::Ident(name))) if keyword.to_string().eq(CONST_KEYWORD) && is_punct(tokens.get(position + 2), ':') => Ok(name.to_string()),
+        _ => match tokens.get(position).or(tokens.last()) {
+            Some(token) => Err((token.to_string(), token.span())),
+            None => Err((String::new(), Span::call_site())),
+        },
+    }
+
+}
+
+/// Get the position of the token starting item after attributes, visibility and qualifiers and if it really starts an item.
+fn item_start(tokens : &[TokenTree], start : usize) -> (usize, bool) {
+
+    // 1. Skip attributes, visibility and qualifiers
+    let mut position = skip_visibility(tokens, skip_attributes(tokens, start));
+    while matches!(tokens.get(position), Some(TokenTree::Ident(ident)) if ITEM_QUALIFIERS.contains(&ident.to_string().as_str())) {
+        position += 1;
+    }
+
+    // 2. Verify that it starts an item
+    let is_item = match (tokens.get(position), macro_group(tokens, position)) {
+        (None, _) => true,  // Attributes only
+        (Some(TokenTree::Punct(punct)), None) if punct.as_char() == ITEM_TERMINATOR => true,  // Empty item
+        (Some(token), Some(_)) => !STATEMENT_MACROS.contains(&token.to_string().as_str()),
+        (Some(TokenTree::Ident(ident)), None) => ITEM_KEYWORDS.contains(&ident.to_string().as_str()),
+        _ => false,
+    };
+
+    (position, is_item)
+
+}
+
+/// Returns the position after the last token of item starting at start.
+fn item_end(tokens : &[TokenTree], start : usize) -> usize {
+
+    // 1. Skip attributes and visibility
+    let position = skip_visibility(tokens, skip_attributes(tokens, start));
+
+    // 2. Find where item ends according to its kind
+    match item_kind(tokens, position) {
+        ItemKind::Terminated => find_terminator(tokens, position),
+        ItemKind::ExternBlock => find_body(tokens, position, false),
+        ItemKind::Macro(group) => match tokens.get(group) {
+            Some(TokenTree::Group(grp)) if grp.delimiter() == Delimiter::Brace => {
+                if is_punct(tokens.get(group + 1), ITEM_TERMINATOR) {   // Optional `;` after braces
+                    group + 2
+                } else {
+                    group + 1
+                }
+            },
+            _ => find_terminator(tokens, group),
+        },
+        ItemKind::Body => find_body(tokens, position, true),
+    }
+
+}
+
+/// Returns the position after the last token of statement starting at start.
+///
+/// Block expressions like `if`, `match` or `{ }` end with their braces unless followed by `.method()` or `?`. Others end at `;`.
+fn statement_end(tokens : &[TokenTree], start : usize) -> usize {
+
+    // 1. Skip attributes and loop label `'label:`
+    let mut position = skip_attributes(tokens, start);
+    if is_punct(tokens.get(position), '\'') && is_punct(tokens.get(position + 2), ':') {
+        position += 3;
+    }
+
+    // 2. Block expressions end with their braces and `else` chain
+    let is_block = match tokens.get(position) {
+        Some(TokenTree::Group(grp)) => grp.delimiter() == Delimiter::Brace,
+        Some(TokenTree::Ident(ident)) => BLOCK_KEYWORDS.contains(&ident.to_string().as_str()),
+        _ => false,
+    };
+
+    if is_block {
+        let mut end = find_body(tokens, position, false);
+        while matches!(tokens.get(end), Some(TokenTree::Ident(ident)) if ident.to_string().eq(ELSE_KEYWORD)) {
+            end = find_body(tokens, end, false);
+        }
+
+        match tokens.get(end) {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == '.' || punct.as_char() == '?' => {},    // Block expression used as value
+            Some(TokenTree::Punct(punct)) if punct.as_char() == ITEM_TERMINATOR => return end + 1,
+            _ => return end,
+        }
+    }
+
+    // 3. Other statements end at `;`
+    find_terminator(tokens, position)
+
+}
+
+/// Get the kind of item from the tokens after attributes and visibility.
+fn item_kind(tokens : &[TokenTree], mut position : usize) -> ItemKind {
+
+    // 1. Skip qualifiers
+    while matches!(tokens.get(position), Some(TokenTree::Ident(ident)) if ITEM_QUALIFIERS.contains(&ident.to_string().as_str())) {
+        position += 1;
+    }
+
+    // 2. Macro invocation `path!(...)` or `macro_rules! name {...}`
+    if let Some(group) = macro_group(tokens, position) {
+        return ItemKind::Macro(group);
+    }
+
+    // 3. Keyword
+    let keyword = |position : usize| match tokens.get(position) {
+        Some(TokenTree::Ident(ident)) => ident.to_string(),
+        _ => String::new(),
+    };
+
+    match keyword(position).as_str() {
+        "const" | "static" => match keyword(position + 1).as_str() {
+            "fn" | "unsafe" | "async" | "extern" => ItemKind::Body,     // const fn
+            _ => ItemKind::Terminated,
+        },
+        "use" | "type" => ItemKind::Terminated,
+        "extern" => match tokens.get(position + 1) {
+            Some(TokenTree::Ident(ident)) if ident.to_string().eq("crate") => ItemKind::Terminated,
+            Some(TokenTree::Literal(_)) => match tokens.get(position + 2) {     // extern "C"
+                Some(TokenTree::Group(_)) => ItemKind::ExternBlock,
+                _ => ItemKind::Body,
+            },
+            Some(TokenTree::Group(_)) => ItemKind::ExternBlock,
+            _ => ItemKind::Body,
+        },
+        _ => ItemKind::Body,
+    }
+
+}
+
+/// Returns the position of macro group if tokens at position are a macro invocation.
+fn macro_group(tokens : &[TokenTree], mut position : usize) -> Option<usize> {
+
+    // 1. Path made of identifiers and `::`
+    if matches!(tokens.get(position), Some(TokenTree::Punct(punct)) if punct.as_char() == ':') {
+        position += 2;  // Absolute path `::path`
+    }
+    loop {
+        match (tokens.get(position), tokens.get(position + 1), tokens.get(position + 2)) {
+            (Some(TokenTree::Ident(_)), Some(TokenTree::Punct(colon)), Some(TokenTree::Punct(_))) if colon.as_char() == ':' => position += 3,
+            (Some(TokenTree::Ident(_)), Some(TokenTree::Punct(bang)), _) if bang.as_char() == MACRO_BANG => break,
+            _ => return None,
+        }
+    }
+
+    // 2. Group, with the name of the macro defined by macro_rules! before it
+    match (tokens.get(position + 2), tokens.get(position + 3)) {
+        (Some(TokenTree::Group(_)), _) => Some(position + 2),
+        (Some(TokenTree::Ident(_)), Some(TokenTree::Group(_))) => Some(position + 3),
+        _ => None,
+    }
+
+}
+
+/// Returns the position after outer attributes `#[...]`.
+fn skip_attributes(tokens : &[TokenTree], mut position : usize) -> usize {
+    while is_punct(tokens.get(position), ATTRIBUTE_HASH) {
+        if is_punct(tokens.get(position + 1), MACRO_BANG) {     // Inner attribute
+            position += 1;
+        }
+        match tokens.get(position + 1) {
+            Some(TokenTree::Group(grp)) if grp.delimiter() == Delimiter::Bracket => position += 2,
+            _ => break,
+        }
+    }
+
+    position
+}
+
+/// Returns the position after visibility `pub`, `pub(crate)`, ...
+fn skip_visibility(tokens : &[TokenTree], mut position : usize) -> usize {
+    if matches!(tokens.get(position), Some(TokenTree::Ident(ident)) if ident.to_string().eq("pub")) {
+        position += 1;
+        if matches!(tokens.get(position), Some(TokenTree::Group(grp)) if grp.delimiter() == Delimiter::Parenthesis) {
+            position += 1;
+        }
+    }
+
+    position
+}
+
+/// Returns the position after the first `;`, or the end of tokens.
+fn find_terminator(tokens : &[TokenTree], position : usize) -> usize {
+    tokens[position..].iter().position(|t| is_punct(Some(t), ITEM_TERMINATOR)).map_or(tokens.len(), |end| position + end + 1)
+}
+
+/// Returns the position after the first braces or `;`.
+///
+/// If generics are tracked, braces inside `<>` like `Foo<{ N }>` are skipped. Otherwise braces of patterns like
+/// `if let Point { x, y } = p` or `for Point { x, y } in v` are skipped until their `=` or `in`.
+fn find_body(tokens : &[TokenTree], position : usize, generics : bool) -> usize {
+
+    let mut angle_depth : usize = 0;
+    let mut is_pattern = false;
+
+    for (i, token) in tokens.iter().enumerate().skip(position) {
+        match token {
+            TokenTree::Group(grp) if grp.delimiter() == Delimiter::Brace && angle_depth == 0 && !is_pattern => return i + 1,
+            TokenTree::Ident(ident) if !generics => match ident.to_string().as_str() {
+                LET_KEYWORD | FOR_KEYWORD => is_pattern = true,
+                IN_KEYWORD => is_pattern = false,
+                _ => {},
+            },
+            TokenTree::Punct(punct) => match punct.as_char() {
+                ITEM_TERMINATOR => return i + 1,
+                PATTERN_ASSIGN if is_pattern && punct.spacing() == Spacing::Alone && !matches!(tokens.get(i.wrapping_sub(1)), Some(TokenTree::Punct(prev)) if prev.spacing() == Spacing::Joint) => is_pattern = false,     // Skip `==` and `..=`
+                '<' if generics => angle_depth += 1,
+                '>' if generics && !is_punct(tokens.get(i.wrapping_sub(1)), '-') => angle_depth = angle_depth.saturating_sub(1),   // Skip `->`
+                _ => {},
+            },
+            _ => {},
+        }
+    }
+
+    tokens.len()
+
+}
+
+/// Returns true if item is a macro invocation with parenthesis or brackets missing `;`.
+fn is_unterminated_macro(item : &[TokenTree]) -> bool {
+    match item {
+        [.., TokenTree::Punct(bang), TokenTree::Group(grp)] => bang.as_char() == MACRO_BANG && grp.delimiter() != Delimiter::Brace,
+        _ => false,
+    }
+}
+
+/// Returns true if token is punct with character.
+#[inline(always)]
+fn is_punct(token : Option<&TokenTree>, c : char) -> bool {
+    matches!(token, Some(TokenTree::Punct(punct)) if punct.as_char() == c)
+}
@@ -81,6 +81,7 @@ SOFTWARE.
 //! 
 //! [Get more examples on the wiki.](https://github.com/NickelAngeStudio/nscfg/wiki/Examples)
 use arm::TargetArm;
+use errors::NSCFGSpanError;
 use proc_macro::{TokenStream, TokenTree, Group, Delimiter};
 
 /// Errors enumeration
@@ -89,20 +90,45 @@ mod errors;
 /// config.toml fetch functions
 mod config;
 
+/// Minimal TOML parser
+mod toml;
+
 /// Arms structure and functions
 mod arm;
 
 /// Syntax tree
 mod syntax;
 
+/// In-source aliases defined with cfg_alias!
+mod alias;
+
+/// Target configuration deduced from triple
+mod target;
+
+/// Items boundaries recognizer
+mod item;
+
+/// target_cfg! options
+mod options;
+
 /// Proc macro source enumeration to determinate matching macro source.
 #[derive(Clone, Copy)]
+#[allow(clippy::enum_variant_names)]
 pub(crate) enum NscfgMacroSource {
-    /// Call come from target_cfg! macro.
-    TargetMacro,
+    /// Call come from target_cfg! macro, with its options.
+    TargetMacro(options::TargetOptions),
 
     /// Call come from match_cfg! macro.
     MatchMacro,
+
+    /// Call come from stmt_cfg! macro.
+    StmtMacro,
+
+    /// Call come from type_cfg! macro.
+    TypeMacro,
+
+    /// Call come from cfg_consts! macro.
+    ConstsMacro,
 }
 
 /// Procedural macro used to declare resource and item outside function.
@@ -113,15 +139,23 @@ pub(crate) enum NscfgMacroSource {
 /// and not all cases are covered with a [wildcard](https://doc.rust-lang.org/reference/patterns.html#wildcard-pattern).
 /// 
 /// Because this behaviour is different from [match](https://doc.rust-lang.org/rust-by-example/flow_control/match.html), 
-/// target_cfg! **WILL PANIC** if used in function (use [match_cfg!] inside function instead).
+/// target_cfg! arms can only contain items and **WILL NOT COMPILE** with statements or expressions (use [match_cfg!] inside function instead).
+/// 
+/// ## Options
+/// Options are written as `#![option, ...]` before arms.
+/// * `first` : Only the first matching arm is included, like [match_cfg!]. A `_` wildcard arm can be added last as fallback.
+/// * `exhaustive` : Compilation fails when no arm matches the configuration, with arm modifiers applied. The error names the configuration not covered. Arms must also cover [supported targets](match_cfg!#supported-targets) if declared.
+/// * `disjoint` : Compilation fails when two arms match the configuration, with arm modifiers applied.
 /// 
 /// **target_cfg! has no runtime cost.**
 /// 
 /// ## Syntax
 /// ```
 /// target_cfg!{
+///     #![option,*]?    // Options
 ///     !? alias* (| &)? !? value:pred* => {},+
 ///     #[cfg(legacy_syntax)] => {},+    // target_cfg! also support legacy syntax
+///     _ => {}?    // Wildcard arm with `first` option
 /// }
 /// ```
 /// [More details on syntax here.](https://github.com/NickelAngeStudio/nscfg/wiki/Syntax)
@@ -188,14 +222,26 @@ pub fn target_cfg(item: TokenStream) -> TokenStream {
     // TokenStream that accumulate content
     let mut content = TokenStream::new();
 
-    // 1. Extract target arms
-    let arms = TargetArm::extract(item.clone(), NscfgMacroSource::TargetMacro);
+    // 1. Extract in-source aliases and options
+    let (options, stream) = match options::TargetOptions::extract(alias::extract_source_aliases(item.clone())) {
+        Ok(extracted) => extracted,
+        Err(errors) => return NSCFGSpanError::to_compile_errors(&errors),
+    };
+
+    // 1.1. Extract target arms
+    let arms = match TargetArm::extract(stream, NscfgMacroSource::TargetMacro(options)) {
+        Ok(arms) => arms,
+        Err(errors) => return alias::defer_to_alias_helper("target_cfg", item, &errors).unwrap_or_else(|| NSCFGSpanError::to_compile_errors(&errors)),
+    };
 
     // 2. For each arm
-    for arm in arms {
+    for arm in arms.iter() {
+
+        // 2.0. Add warnings
+        content.extend(arm.warning_ts.clone());
 
         // 2.1. Split item into vector of items
-        let items = syntax::split_items(arm.content.clone());
+        let items = item::split_items(arm.content.clone());
 
         // 2.2. For each item in vector of items
         for item in items {
@@ -210,7 +256,20 @@ pub fn target_cfg(item: TokenStream) -> TokenStream {
         }
     }
 
-    // 3. Return content.
+    // 3. Add error when no arm matches
+    if options.exhaustive {
+        content.extend(TargetArm::generate_exhaustive_ts(&arms));
+    }
+
+    // 3.1. Add error when more than one arm matches
+    if options.disjoint {
+        content.extend(TargetArm::generate_disjoint_ts(&arms));
+    }
+
+    // 3.2. Track configuration files read
+    content.extend(TargetArm::generate_tracking_ts());
+
+    // 4. Return content.
     content
 
 }
@@ -223,7 +282,7 @@ pub fn target_cfg(item: TokenStream) -> TokenStream {
 /// to define conditional compilation in a function.  The first matching arm is evaluated and all possible values must be covered with a [wildcard](https://doc.rust-lang.org/reference/patterns.html#wildcard-pattern).
 /// 
 /// This behaviour is the same as [match](https://doc.rust-lang.org/rust-by-example/flow_control/match.html), 
-/// thus match_cfg! can be used inside a function (while [target_cfg!] will [panic!]).
+/// thus match_cfg! can be used inside a function (while [target_cfg!] will raise a compile error).
 /// 
 /// **match_cfg! has no runtime cost.**
 /// 
@@ -237,6 +296,11 @@ pub fn target_cfg(item: TokenStream) -> TokenStream {
 /// ```
 /// [More details on syntax here.](https://github.com/NickelAngeStudio/nscfg/wiki/Syntax)
 /// 
+/// ## Supported targets
+/// Target triples supported by the crate can be declared with `targets = ["x86_64-unknown-linux-gnu", ...]` in 
+/// `[package.metadata.nscfg]` or `nscfg_targets = "x86_64-unknown-linux-gnu, ..."` in config.toml. Arms must then cover each of them 
+/// and the wildcard arm isn't mandatory anymore. Arms depending on features or other keys a target can't decide don't cover it.
+/// 
 /// ## Example
 /// **This**
 /// ```
@@ -293,11 +357,20 @@ pub fn match_cfg(item: TokenStream) -> TokenStream {
      // TokenStream that accumulate content
      let mut content = TokenStream::new();
 
-     // 1. Extract target arms
-     let arms = TargetArm::extract(item.clone(), NscfgMacroSource::MatchMacro);
+     // 1. Extract in-source aliases and target arms
+     let arms = match TargetArm::extract(alias::extract_source_aliases(item.clone()), NscfgMacroSource::MatchMacro) {
+         Ok(arms) => arms,
+         Err(errors) => return alias::defer_to_alias_helper("match_cfg", item, &errors).unwrap_or_else(|| NSCFGSpanError::to_compile_errors_expr(&errors)),
+     };
  
+     // 1.1. Track configuration files read
+     content.extend(TargetArm::generate_tracking_ts());
+
      // 2. For each arm
      for arm in arms {
+         // 2.0. Add warnings
+         content.extend(arm.warning_ts.clone());
+
          // 2.1. Add cfg header.
          content.extend(arm.cfg_ts.clone()); 
  
@@ -311,6 +384,270 @@ pub fn match_cfg(item: TokenStream) -> TokenStream {
 }
 
 
+/// Procedural macro used to declare statements inside a function.
+/// 
+/// ## Description
+/// stmt_cfg! is the [target_cfg!] of function bodies : **any matching arm WILL be included** and no wildcard arm is needed.
+/// Each statement of arms gets its own `#[cfg]` so `let` bindings and items are visible to the code following the macro.
+/// 
+/// Contrary to [match_cfg!], stmt_cfg! expands to statements and not to a value.
+/// 
+/// **stmt_cfg! has no runtime cost.**
+/// 
+/// ## Syntax
+/// ```
+/// stmt_cfg!{
+///     !? alias* (| &)? !? value:pred* => {},+
+///     #[cfg(legacy_syntax)] => {},+    // stmt_cfg! also support legacy syntax
+/// }
+/// ```
+/// [More details on syntax here.](https://github.com/NickelAngeStudio/nscfg/wiki/Syntax)
+/// 
+/// ## Example
+/// **This**
+/// ```
+/// pub fn foo(){
+///     let mut a = 0;
+/// 
+///     #[cfg(linux)]
+///     let path = "/tmp";
+///     #[cfg(linux)]
+///     {
+///         a += 10;
+///     }
+///     #[cfg(windows)]
+///     let path = "C:\\Temp";
+///     #[cfg(debug_assertions)]
+///     {
+///         a += 1;
+///     }
+/// 
+///     println!("{} {}", path, a);
+/// }
+/// ```
+/// **becomes**
+/// ```
+/// pub fn foo(){
+///     let mut a = 0;
+/// 
+///     stmt_cfg!{
+///         linux => {
+///             let path = "/tmp";
+///             a += 10;
+///         },
+///         windows => let path = "C:\\Temp",
+///         debug => a += 1,     // Included with linux or windows arm
+///     }
+/// 
+///     println!("{} {}", path, a);
+/// }
+/// ```
+/// [More examples here.](https://github.com/NickelAngeStudio/nscfg/wiki/Examples)
+#[proc_macro]
+pub fn stmt_cfg(item: TokenStream) -> TokenStream {
+
+    // TokenStream that accumulate content
+    let mut content = TokenStream::new();
+
+    // 1. Extract in-source aliases and target arms
+    let arms = match TargetArm::extract(alias::extract_source_aliases(item.clone()), NscfgMacroSource::StmtMacro) {
+        Ok(arms) => arms,
+        Err(errors) => return alias::defer_to_alias_helper("stmt_cfg", item, &errors).unwrap_or_else(|| NSCFGSpanError::to_compile_errors_expr(&errors)),
+    };
+
+    // 2. For each arm
+    for arm in arms.iter() {
+        // 2.0. Add warnings
+        content.extend(arm.warning_ts.clone());
+
+        // 2.1. Add each statement with cfg header
+        for statement in item::split_statements(arm.content.clone()) {
+            content.extend(arm.cfg_ts.clone());
+            content.extend(statement);
+        }
+    }
+
+    // 3. Track configuration files read
+    content.extend(TargetArm::generate_tracking_ts());
+
+    // 4. Return content.
+    content
+
+}
+
+
+/// Procedural macro used to select a type wherever a type is expected.
+/// 
+/// ## Description
+/// type_cfg! resolves to the type of the first matching arm, like [match_cfg!] does with values. It can be used
+/// for fields, function signatures, generics and type aliases. All possible values must be covered with a wildcard arm 
+/// unless [supported targets](macro.match_cfg.html#supported-targets) are declared.
+/// 
+/// type_cfg! expands to [cfg_select!](https://doc.rust-lang.org/std/macro.cfg_select.html) since attributes can't be written on types, 
+/// thus it requires Rust 1.95 or newer. Precedence warnings aren't shown by type_cfg! since a type can't contain the item displaying them.
+/// 
+/// **type_cfg! has no runtime cost.**
+/// 
+/// ## Syntax
+/// ```
+/// type_cfg!{
+///     !? alias* (| &)? !? value:pred* => type,+
+///     #[cfg(legacy_syntax)] => type,+    // type_cfg! also support legacy syntax
+///     _ => type+?     // Mandatory wildcard arm
+/// }
+/// ```
+/// [More details on syntax here.](https://github.com/NickelAngeStudio/nscfg/wiki/Syntax)
+/// 
+/// ## Example
+/// **This**
+/// ```
+/// #[cfg(windows)]
+/// pub type RawHandle = *mut c_void;
+/// #[cfg(all(not(windows), unix))]
+/// pub type RawHandle = i32;
+/// #[cfg(all(not(windows), not(unix)))]   // This would be a wildcard arm.
+/// pub type RawHandle = ();
+/// 
+/// pub struct File {
+///     handle : RawHandle,
+/// }
+/// ```
+/// **becomes**
+/// ```
+/// pub struct File {
+///     handle : type_cfg!{ windows => *mut c_void, unix => i32, _ => () },
+/// }
+/// ```
+/// [More examples here.](https://github.com/NickelAngeStudio/nscfg/wiki/Examples)
+#[proc_macro]
+pub fn type_cfg(item: TokenStream) -> TokenStream {
+
+    // TokenStream that accumulate content
+    let mut content = TokenStream::new();
+
+    // 1. Extract in-source aliases and target arms
+    let arms = match TargetArm::extract(alias::extract_source_aliases(item.clone()), NscfgMacroSource::TypeMacro) {
+        Ok(arms) => arms,
+        Err(errors) => return alias::defer_to_alias_helper("type_cfg", item, &errors).unwrap_or_else(|| NSCFGSpanError::to_compile_errors(&errors)),
+    };
+
+    // 2. For each arm, add `predicate => { type }`. Warnings are items that can't be written in a type.
+    for arm in arms {
+        content.extend(arm.cfg_predicate());
+        content.extend("=>".parse::<TokenStream>().unwrap());
+        content.extend(TokenStream::from(TokenTree::from(Group::new(Delimiter::Brace, arm.content.clone()))));
+    }
+
+    // 3. Select type with cfg_select!
+    let mut select = "::core::cfg_select!".parse::<TokenStream>().unwrap();
+    select.extend(TokenStream::from(TokenTree::from(Group::new(Delimiter::Brace, content))));
+    select
+
+}
+
+
+/// Procedural macro used to declare the same consts for each platform.
+/// 
+/// ## Description
+/// cfg_consts! arms each define the same named list of `const` items. Like [match_cfg!], only the first matching arm 
+/// is included and a wildcard arm giving default values is needed unless [supported targets](macro.match_cfg.html#supported-targets) are declared.
+/// 
+/// Compilation fails if an arm doesn't define a const defined by another arm, so a const can't be forgotten for a platform.
+/// Each const is declared once per arm with its `#[cfg]`.
+/// 
+/// **cfg_consts! has no runtime cost.**
+/// 
+/// ## Syntax
+/// ```
+/// cfg_consts!{
+///     !? alias* (| &)? !? value:pred* => { const NAME : Type = value;+ },+
+///     #[cfg(legacy_syntax)] => { const NAME : Type = value;+ },+    // cfg_consts! also support legacy syntax
+///     _ => { const NAME : Type = value;+ }+?     // Mandatory wildcard arm
+/// }
+/// ```
+/// [More details on syntax here.](https://github.com/NickelAngeStudio/nscfg/wiki/Syntax)
+/// 
+/// ## Example
+/// **This**
+/// ```
+/// #[cfg(windows)]
+/// pub const PATH_SEPARATOR : char = '\\';
+/// #[cfg(all(not(windows), unix))]
+/// pub const PATH_SEPARATOR : char = '/';
+/// #[cfg(all(not(windows), not(unix)))]   // This would be a wildcard arm.
+/// pub const PATH_SEPARATOR : char = '/';
+/// 
+/// #[cfg(windows)]
+/// pub const MAX_PATH : usize = 260;
+/// #[cfg(all(not(windows), unix))]
+/// pub const MAX_PATH : usize = 4096;
+/// #[cfg(all(not(windows), not(unix)))]   // This would be a wildcard arm.
+/// pub const MAX_PATH : usize = 256;
+/// ```
+/// **becomes**
+/// ```
+/// cfg_consts!{
+///     windows => {
+///         pub const PATH_SEPARATOR : char = '\\';
+///         pub const MAX_PATH : usize = 260;
+///     },
+///     unix => {
+///         pub const PATH_SEPARATOR : char = '/';
+///         pub const MAX_PATH : usize = 4096;
+///     },
+///     _ => {
+///         pub const PATH_SEPARATOR : char = '/';
+///         pub const MAX_PATH : usize = 256;
+///     }
+/// }
+/// ```
+/// [More examples here.](https://github.com/NickelAngeStudio/nscfg/wiki/Examples)
+#[proc_macro]
+pub fn cfg_consts(item: TokenStream) -> TokenStream {
+
+    // TokenStream that accumulate content
+    let mut content = TokenStream::new();
+
+    // 1. Extract in-source aliases and target arms
+    let arms = match TargetArm::extract(alias::extract_source_aliases(item.clone()), NscfgMacroSource::ConstsMacro) {
+        Ok(arms) => arms,
+        Err(errors) => return alias::defer_to_alias_helper("cfg_consts", item, &errors).unwrap_or_else(|| NSCFGSpanError::to_compile_errors(&errors)),
+    };
+
+    // 2. Get consts of each arm with their name and add warnings
+    let mut consts : Vec<Vec<(String, TokenStream)>> = Vec::new();
+    for arm in arms.iter() {
+        content.extend(arm.warning_ts.clone());
+        consts.push(item::split_items(arm.content.clone()).into_iter().filter_map(|item| item::const_name(item.clone()).ok().map(|name| (name, item))).collect());
+    }
+
+    // 3. For each name, add the const of each arm with cfg header and cfg_attr. Arms define the same names.
+    let mut names : Vec<&String> = Vec::new();
+    for (name, _) in consts.first().into_iter().flatten() {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    for name in names {
+        for (arm, arm_consts) in arms.iter().zip(consts.iter()) {
+            for (_, item) in arm_consts.iter().filter(|(const_name, _)| const_name.eq(name)) {
+                content.extend(arm.cfg_ts.clone());
+                content.extend(arm.attr_ts.clone());
+                content.extend(item.clone());
+            }
+        }
+    }
+
+    // 4. Track configuration files read
+    content.extend(TargetArm::generate_tracking_ts());
+
+    // 5. Return content.
+    content
+
+}
+
+
 /// Attribute macro like [cfg](https://doc.rust-lang.org/rust-by-example/attribute/cfg.html) with [simplified syntax](https://github.com/NickelAngeStudio/nscfg/wiki/Syntax) used for one item.
 /// 
 /// ## Description
@@ -359,4 +696,57 @@ pub fn meta_cfg(attr: TokenStream, item: TokenStream) -> TokenStream {
     // 2. Generate tokenstream with target_cfg! macro
     target_cfg(stream)
 
-}
\ No newline at end of file
+}
+
+
+/// Procedural macro used to define aliases in source code.
+/// 
+/// ## Description
+/// cfg_alias! defines aliases next to where they are used instead of config.toml or Cargo.toml. Each alias generates 
+/// a crate-local `macro_rules!` helper named `nscfg_alias_<name>` that [target_cfg!], [match_cfg!] and [macro@meta_cfg] 
+/// call when they can't find an alias elsewhere. An alias found nowhere is reported by rustc as a missing `nscfg_alias_<name>` macro.
+/// 
+/// Like any `macro_rules!`, aliases can only be used after cfg_alias! in the source. Define them at the top of 
+/// lib.rs or main.rs to use them in the whole crate. An alias already defined in environment, configuration or 
+/// built-in aliases can't be defined again.
+/// 
+/// Helpers call macros back from `::nscfg`, or from the name given to nscfg in Cargo.toml dependencies. When nscfg is 
+/// re-exported by another crate, give its path with `#![crate = path]` before definitions.
+/// 
+/// Aliases can take parameters written as `{param}` in the expression, like `simd(arch, feat) = {arch}:ar & {feat}:tf;` 
+/// used as `simd(aarch64, neon)`. The count of arguments must match the count of parameters.
+/// 
+/// **cfg_alias! has no runtime cost.**
+/// 
+/// ## Syntax
+/// ```ignore
+/// cfg_alias!{
+///     (#![crate = path])?
+///     name ((param,+))? = !? alias* (| &)? !? value:pred*;+
+/// }
+/// ```
+/// 
+/// ## Example
+/// ```
+/// # use nscfg::{cfg_alias, meta_cfg};
+/// cfg_alias!{
+///     render_backend = vulkan:ft | metal:ft;
+///     apple_render = render_backend & macos:os;
+///     simd(arch, feat) = {arch}:ar & {feat}:tf;
+/// }
+/// 
+/// #[meta_cfg(apple_render)]
+/// pub fn render() {}
+/// 
+/// #[meta_cfg(simd(aarch64, neon))]
+/// pub fn render_neon() {}
+/// ```
+#[proc_macro]
+pub fn cfg_alias(item: TokenStream) -> TokenStream {
+
+    match alias::generate_alias_helpers(item) {
+        Ok(helpers) => helpers,
+        Err(errors) => NSCFGSpanError::to_compile_errors(&errors),
+    }
+
+}
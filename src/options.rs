@@ -0,0 +1,93 @@
+/*
+Copyright (c) 2024  NickelAnge.Studio
+Email               mathieu.grenier@nickelange.studio
+Git                 https://github.com/NickelAngeStudio/nswnd
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+use proc_macro::{TokenStream, TokenTree, Delimiter};
+
+use crate::{errors::{NSCFGError, NSCFGSpanError}, arm::ARM_SEPARATOR};
+
+/// Only the first matching arm is included.
+pub(crate) const OPTION_FIRST : &str = "first";
+
+/// Generate an error when no arm matches.
+pub(crate) const OPTION_EXHAUSTIVE : &str = "exhaustive";
+
+/// Generate an error when two arms match.
+pub(crate) const OPTION_DISJOINT : &str = "disjoint";
+
+/// Every option available, used for errors.
+pub(crate) const TARGET_OPTIONS : [&str; 3] = [OPTION_FIRST, OPTION_EXHAUSTIVE, OPTION_DISJOINT];
+
+/// Options of target_cfg! written as `#![option, ...]` before arms.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct TargetOptions {
+    pub first : bool,       // Only the first matching arm is included. Allows a wildcard arm.
+    pub exhaustive : bool,  // Compilation fails when no arm matches.
+    pub disjoint : bool,    // Compilation fails when more than one arm matches.
+}
+
+impl TargetOptions {
+    /// Extract options at the beginning of stream and return them with the remaining tokens.
+    ///
+    /// Options can be written in one `#![first, ...]` or many `#![first] #![...]` inner attributes.
+    ///
+    /// Error(s)
+    /// Returns Err(Vec<[NSCFGSpanError]>) for each unknown option.
+    pub fn extract(stream : TokenStream) -> Result<(TargetOptions, TokenStream), Vec<NSCFGSpanError>> {
+
+        let tokens : Vec<TokenTree> = stream.into_iter().collect();
+        let mut options = TargetOptions::default();
+        let mut errors : Vec<NSCFGSpanError> = Vec::new();
+        let mut position = 0;
+
+        // 1. Read each #![option, ...]
+        while let [TokenTree::Punct(hash), TokenTree::Punct(bang), TokenTree::Group(attribute), ..] = &tokens[position..] {
+            if hash.as_char() != '#' || bang.as_char() != '!' || attribute.delimiter() != Delimiter::Bracket {
+                break;
+            }
+
+            // 2. Set each option
+            for token in attribute.stream() {
+                match &token {
+                    TokenTree::Ident(ident) => match ident.to_string().as_str() {
+                        OPTION_FIRST => options.first = true,
+                        OPTION_EXHAUSTIVE => options.exhaustive = true,
+                        OPTION_DISJOINT => options.disjoint = true,
+                        option => errors.push(NSCFGError::UnknownOption(option.to_string()).at("", ident.span())),
+                    },
+                    TokenTree::Punct(punct) if punct.as_char() == ARM_SEPARATOR => {},
+                    _ => errors.push(NSCFGError::UnknownOption(token.to_string()).at("", token.span())),
+                }
+            }
+
+            position += 3;
+        }
+
+        if errors.is_empty() {
+            Ok((options, tokens[position..].iter().cloned().collect()))
+        } else {
+            Err(errors)
+        }
+
+    }
+}
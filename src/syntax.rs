@@ -1,13 +1,16 @@
 // Syntax tree used to generate configuration from TokenStream.
 
-use std::{rc::Rc};
-use proc_macro::{TokenStream, TokenTree};
+use std::rc::Rc;
+use proc_macro::{TokenStream, TokenTree, Delimiter, Span, Group};
 
-use crate::{errors::NSCFGError, config::{get_nscfg_alias, get_nscfg_predicate}};
+use crate::{errors::{NSCFGError, NSCFGSpanError, NSCFGWarning}, config::{get_nscfg_alias, DefinitionSource, get_nscfg_predicate, get_nscfg_leaf_predicate, get_max_alias_depth, RANGE_DOMAINS, RANGE_POINTER_VALUE, RANGE_POINTER_PREDICATE}, target::TargetCfg};
 
 /// SyntaxTreeNode in a RC 
 pub(crate) type Node = Rc<SyntaxTreeNode>;
 
+/// Parse function of a precedence level with tokens, span of errors and chain of aliases being expanded.
+type ParseFn = fn(&[TokenTree], Span, &[String]) -> Result<Node, Vec<NSCFGSpanError>>;
+
 /// Negative symbol
 pub(crate) const NEGATIVE_SYMBOL : char = '!';
 
@@ -17,21 +20,36 @@ pub(crate) const AND_SYMBOL : char = '&';
 /// Symbol for OR.
 pub(crate) const OR_SYMBOL : char = '|';
 
+/// Symbol between key and value of raw `key = "value"` leaves.
+const KEY_VALUE_SYMBOL : char = '=';
+
+/// Keyword of raw `cfg(...)` leaves.
+const RAW_CFG_KEYWORD : &str = "cfg";
+
+/// Predicate of raw `key="value":kv` leaves.
+const RAW_KV_PREDICATE : &str = "kv";
+
+/// Separator of parametrized alias arguments.
+const ARGUMENT_SEPARATOR : char = ',';
+
 
 /// Syntax tree node used to parse attribute tokens.
+/// 
+/// Precedence from tightest to loosest is `!`, `&` then `|`. Chains of the same operator are
+/// flattened into a single n-ary node (`a | b | c` becomes `any(a,b,c)`).
 #[derive(Debug)]
 pub(crate) enum SyntaxTreeNode {
     /// A Not node
     NOT(Node),
 
     /// A or(|) operation
-    ANY(Node, Node),
+    ANY(Vec<Node>),
 
     /// A and(&) operation
-    ALL(Node, Node),
+    ALL(Vec<Node>),
 
-    /// End leaf of the tree
-    LEAF(String)
+    /// End leaf of the tree with label and predicate
+    LEAF(String, String)
 }
 
 impl ToString for SyntaxTreeNode {
@@ -39,13 +57,9 @@ impl ToString for SyntaxTreeNode {
     fn to_string(&self) -> String {
         match self {
             SyntaxTreeNode::NOT(node) => format!("not({})", node.to_string()),
-            SyntaxTreeNode::ANY(left_node, right_node) => format!("any({},{})", left_node.to_string(), right_node.to_string()),
-            SyntaxTreeNode::ALL(left_node, right_node) => format!("all({},{})", left_node.to_string(), right_node.to_string()),
-            SyntaxTreeNode::LEAF(label) => 
-                match get_nscfg_predicate(&label.as_str()) {
-                    Ok(predicate) => format!("{}", predicate),
-                    Err(err) => panic!("{}", err.message(label)),
-                },
+            SyntaxTreeNode::ANY(nodes) => format!("any({})", join_nodes(nodes)),
+            SyntaxTreeNode::ALL(nodes) => format!("all({})", join_nodes(nodes)),
+            SyntaxTreeNode::LEAF(_, predicate) => predicate.clone(),
         }
     }
 }
@@ -53,241 +67,655 @@ impl ToString for SyntaxTreeNode {
 impl SyntaxTreeNode {
     /// Create a NOT SyntaxTreeNode
     pub fn not_node(child : Node) -> Node {
-        Rc::new(SyntaxTreeNode::NOT(child.clone()))
+        Rc::new(SyntaxTreeNode::NOT(child))
     }
 
     /// Create an ALL SyntaxTreeNode
-    pub fn all_node(left : Node, right : Node) -> Node {
-        Rc::new(SyntaxTreeNode::ALL(left.clone(), right.clone()))
+    pub fn all_node(children : Vec<Node>) -> Node {
+        Rc::new(SyntaxTreeNode::ALL(children))
     }
 
     /// Create an ANY SyntaxTreeNode
-    pub fn any_node(left : Node, right : Node) -> Node {
-        Rc::new(SyntaxTreeNode::ANY(left.clone(), right.clone()))
+    pub fn any_node(children : Vec<Node>) -> Node {
+        Rc::new(SyntaxTreeNode::ANY(children))
     }
 
     /// Generate a SyntaxTreeNode from token stream.
-    pub(crate) fn generate(stream : TokenStream) -> Node {
-
-        match split_tokenstream_at_operator(stream.clone()){
-            // Means we have to split 
-            Some((operator, left, right)) => 
-                match operator {
-                    AND_SYMBOL => {    // ALL node
-                        return Self::all_node(Self::generate(left), Self::generate(right));
-                    }
-                    OR_SYMBOL => {    // ANY node
-                        return Self::any_node(Self::generate(left), Self::generate(right));
-                    },
-                    _ =>  panic!("{}", NSCFGError::InvalidCharacter(String::from(operator)).message(&stream.to_string())),
-                },
-            // No split. Must evaluate if not node, etc...
-            None => {
-                // Is NOT node?
-                let (symbol, content) = extract_negative_symbol(stream.clone());
-                
-                if is_not_node(symbol) {
-                    // Create a NOT node
-                    return Self::not_node(Self::generate(content));
-                } else {
-
-                    // Extract group
-                    match extract_group(content.clone()) {
-                        Some(group) => return Self::generate(group),
-                        None => {
-                            // Verify that node isn't empty.
-                            if content.to_string().eq("") { // Make sure node isn't empty
-                                panic!("{}", NSCFGError::EmptyNode.message(&content.to_string()));
-                            }
-
-                            match content.to_string().find(":"){
-                                Some(pos) => {    // End LEAF reached
-                                    match content.to_string()[..pos].trim().find(" "){    // Make sure node doesn't contains spaces.
-                                        Some(_) => panic!("{}", NSCFGError::MissingOperator.message(&content.to_string())),
-                                        None => {},
-                                    }
-
-                                    return Rc::new(SyntaxTreeNode::LEAF(content.to_string()));
-                                },
-                                None => {   // Unwrap alias
-                                    match content.to_string().find(" "){    // Make sure node doesn't contains spaces.
-                                        Some(_) => panic!("{}", NSCFGError::MissingOperator.message(&content.to_string())),
-                                        None => {},
-                                    }
-                                    match get_nscfg_alias(&content.to_string()) {
-                                        Ok(alias) => Self::generate(alias.parse().unwrap()),
-                                        Err(err) => panic!("{}", err.message(&stream.to_string())),
-                                    }
-                                },
-                            }
-                        },
+    /// 
+    /// Error(s)
+    /// Returns Err(Vec<[NSCFGSpanError]>) with every empty node, invalid character, missing operator, unknown alias, 
+    /// alias cycle and unknown predicate found, each located on the faulty tokens. Span given locates errors of an empty stream.
+    pub(crate) fn generate(stream : TokenStream, span : Span) -> Result<Node, Vec<NSCFGSpanError>> {
+        let tokens : Vec<TokenTree> = stream.into_iter().collect();
+        parse_any(&tokens, span, &[])
+    }
+
+    /// Generate a SyntaxTreeNode from `#[cfg()]` predicates like those of legacy arms. Leaves label is their predicate.
+    /// 
+    /// Returns None if predicates aren't `all()`, `any()`, `not()`, `key` or `key = "value"`.
+    pub(crate) fn from_cfg(stream : TokenStream) -> Option<Node> {
+        let tokens : Vec<TokenTree> = stream.into_iter().collect();
+
+        match tokens.as_slice() {
+            [TokenTree::Ident(operator), TokenTree::Group(group)] if group.delimiter() == Delimiter::Parenthesis => {
+                let mut children : Vec<Node> = Vec::new();
+                for child in group.stream().into_iter().collect::<Vec<TokenTree>>().split(|t| matches!(t, TokenTree::Punct(punct) if punct.as_char() == ',')) {
+                    if !child.is_empty() {  // Last `,` is optional
+                        children.push(Self::from_cfg(child.iter().cloned().collect())?);
                     }
                 }
+
+                match (operator.to_string().as_str(), children.len()) {
+                    ("all", _) => Some(Self::all_node(children)),
+                    ("any", _) => Some(Self::any_node(children)),
+                    ("not", 1) => Some(Self::not_node(children.remove(0))),
+                    _ => None,
+                }
+            },
+            [TokenTree::Ident(_)] | [TokenTree::Ident(_), TokenTree::Punct(_), TokenTree::Literal(_)] => {
+                let predicate = tokens.iter().cloned().collect::<TokenStream>().to_string();
+                Some(Rc::new(SyntaxTreeNode::LEAF(predicate.clone(), predicate)))
+            },
+            _ => None,
+        }
+    }
+
+    /// Decide if node is true for target.
+    /// 
+    /// Returns None if it depends on a key not decided by target, like `feature`.
+    pub(crate) fn evaluate(&self, target : &TargetCfg) -> Option<bool> {
+        match self {
+            SyntaxTreeNode::NOT(node) => node.evaluate(target).map(|value| !value),
+            SyntaxTreeNode::ANY(nodes) => evaluate_nodes(nodes, target, true),
+            SyntaxTreeNode::ALL(nodes) => evaluate_nodes(nodes, target, false),
+            SyntaxTreeNode::LEAF(_, predicate) => match predicate.split_once('=') {
+                Some((key, value)) => target.evaluate(key.trim(), Some(value.trim().trim_matches('"'))),
+                None => target.evaluate(predicate.trim(), None),
+            },
+        }
+    }
+
+    /// Get predicates of leaves in order of appearance.
+    pub(crate) fn leaves(&self) -> Vec<String> {
+        match self {
+            SyntaxTreeNode::NOT(node) => node.leaves(),
+            SyntaxTreeNode::ANY(nodes) | SyntaxTreeNode::ALL(nodes) => nodes.iter().flat_map(|node| node.leaves()).collect(),
+            SyntaxTreeNode::LEAF(_, predicate) => vec![predicate.clone()],
+        }
+    }
+
+    /// Write the node with labels instead of predicates. 
+    /// 
+    /// Nested nodes of the same operator are merged and double negations are removed so that 
+    /// two trees with the same meaning give the same string.
+    fn normalize(&self) -> String {
+        match self {
+            SyntaxTreeNode::NOT(node) => match node.as_ref() {
+                SyntaxTreeNode::NOT(inner) => inner.normalize(),    // !!a == a
+                _ => format!("not({})", node.normalize()),
             },
+            SyntaxTreeNode::ANY(nodes) => format!("any({})", flatten_nodes(nodes, true).join(",")),
+            SyntaxTreeNode::ALL(nodes) => format!("all({})", flatten_nodes(nodes, false).join(",")),
+            SyntaxTreeNode::LEAF(label, _) => label.replace(' ', ""),
+        }
+    }
+
+    /// Verify if arm tokens meaning changed with operator precedence. 
+    /// 
+    /// Returns Some([NSCFGWarning::PrecedenceChanged]) if tokens were interpreted differently before precedence was introduced.
+    pub(crate) fn precedence_warning(stream : TokenStream) -> Option<NSCFGWarning> {
+        
+        // 1. Generate tree without precedence. If it fails, there is nothing to compare.
+        let previous = generate_without_precedence(stream.clone(), &[])?.normalize();
+
+        // 2. Generate tree with precedence
+        let current = Self::generate(stream.clone(), Span::call_site()).ok()?.normalize();
+
+        // 3. Compare both meaning
+        if previous.eq(&current) {
+            None
+        } else {
+            Some(NSCFGWarning::PrecedenceChanged(stream.to_string(), previous, current))
         }
 
     }
 
 }
 
-/// Extract a group from token stream
+/// Join children nodes string with `,`.
 #[inline(always)]
-fn extract_group(stream : TokenStream) -> Option<TokenStream> {
-    for t in stream {
-        match t {
-            TokenTree::Group(group) => return Some(group.stream()),
-            _ => {},
+fn join_nodes(nodes : &[Node]) -> String {
+    nodes.iter().map(|node| node.to_string()).collect::<Vec<String>>().join(",")
+}
+
+/// Decide children nodes for target. Any is true if a child is true, all is false if a child is false.
+/// 
+/// Returns None if result depends on a child that can't be decided.
+#[inline(always)]
+fn evaluate_nodes(nodes : &[Node], target : &TargetCfg, is_any : bool) -> Option<bool> {
+    let mut decided = true;
+
+    for node in nodes {
+        match node.evaluate(target) {
+            Some(value) if value == is_any => return Some(is_any),
+            Some(_) => {},
+            None => decided = false,
+        }
+    }
+
+    if decided { Some(!is_any) } else { None }
+}
+
+/// Get normalized children, merging children that are of the same operator than parent.
+#[inline(always)]
+fn flatten_nodes(nodes : &[Node], is_any : bool) -> Vec<String> {
+    let mut flatten : Vec<String> = Vec::new();
+
+    for node in nodes {
+        match (node.as_ref(), is_any) {
+            (SyntaxTreeNode::ANY(children), true) | (SyntaxTreeNode::ALL(children), false) => flatten.extend(flatten_nodes(children, is_any)),
+            _ => flatten.push(node.normalize()),
         }
     }
-    None
+
+    flatten
 }
 
-/// Extract ! at the beginning of node.
+/// Returns the span of the first and last tokens. Default to span given if tokens are empty.
+#[inline(always)]
+pub(crate) fn tokens_span(tokens : &[TokenTree], span : Span) -> (Span, Span) {
+    match (tokens.first(), tokens.last()) {
+        (Some(first), Some(last)) => (first.span(), last.span()),
+        _ => (span, span),
+    }
+}
+
+/// Split tokens at each top level operator. Groups are single tokens so only top level operators are found.
 /// 
-/// Returns a pair containing ! tokenstream and the rest of the stream without !.
+/// Each operand comes with the span of an operator next to it, used to locate empty operand.
 #[inline(always)]
-pub(crate) fn extract_negative_symbol(stream: TokenStream) -> (TokenStream, TokenStream) {
+fn split_at_operator(tokens : &[TokenTree], operator : char, span : Span) -> Vec<(&[TokenTree], Span)> {
+    let mut operands : Vec<(&[TokenTree], Span)> = Vec::new();
+    let mut start = 0;
+    let mut previous = span;
+
+    for (i, t) in tokens.iter().enumerate() {
+        if let TokenTree::Punct(punct) = t {
+            if punct.as_char() == operator {
+                operands.push((&tokens[start..i], if operands.is_empty() { punct.span() } else { previous }));
+                previous = punct.span();
+                start = i + 1;
+            }
+        }
+    }
+    operands.push((&tokens[start..], previous));
 
-    // Symbol Tokenstream
-    let mut symbol = TokenStream::new();
+    operands
+}
+
+/// Parse every operand, accumulating errors of all operands.
+#[inline(always)]
+fn parse_operands(operands : Vec<(&[TokenTree], Span)>, chain : &[String], parse : ParseFn) -> Result<Vec<Node>, Vec<NSCFGSpanError>> {
+    let mut nodes : Vec<Node> = Vec::new();
+    let mut errors : Vec<NSCFGSpanError> = Vec::new();
+
+    for (operand, span) in operands {
+        match parse(operand, span, chain) {
+            Ok(node) => nodes.push(node),
+            Err(err) => errors.extend(err),
+        }
+    }
 
-    // Content Tokenstream
-    let mut content = TokenStream::new();
+    if errors.is_empty() {
+        Ok(nodes)
+    } else {
+        Err(errors)
+    }
+}
 
-    // Flag that show if in symbol right now.
-    let mut is_symbol = true;
+/// Parse a `|` chain. Lowest precedence.
+fn parse_any(tokens : &[TokenTree], span : Span, chain : &[String]) -> Result<Node, Vec<NSCFGSpanError>> {
+    let mut operands = split_at_operator(tokens, OR_SYMBOL, span);
 
+    if operands.len() == 1 {
+        let (operand, span) = operands.remove(0);
+        parse_all(operand, span, chain)
+    } else {
+        Ok(SyntaxTreeNode::any_node(parse_operands(operands, chain, parse_all)?))
+    }
+}
 
-    for t in stream.clone() {
-        if is_symbol {
-            match t.clone() {
-                proc_macro::TokenTree::Punct(punc) => {
-                    match punc.as_char() {
-                        NEGATIVE_SYMBOL => symbol.extend(TokenStream::from(t)),
-                        _ => panic!("{}", NSCFGError::InvalidCharacter(String::from(punc.as_char())).message(&stream.to_string())),
-                    }
-                },
-                _ => {
-                    is_symbol = false;
-                    content.extend(TokenStream::from(t));
-                },
+/// Parse a `&` chain. Binds tighter than `|`.
+fn parse_all(tokens : &[TokenTree], span : Span, chain : &[String]) -> Result<Node, Vec<NSCFGSpanError>> {
+    let mut operands = split_at_operator(tokens, AND_SYMBOL, span);
+
+    if operands.len() == 1 {
+        let (operand, span) = operands.remove(0);
+        parse_not(operand, span, chain)
+    } else {
+        Ok(SyntaxTreeNode::all_node(parse_operands(operands, chain, parse_not)?))
+    }
+}
+
+/// Parse `!` prefixes. Binds the tightest, each `!` is a NOT node.
+fn parse_not(tokens : &[TokenTree], span : Span, chain : &[String]) -> Result<Node, Vec<NSCFGSpanError>> {
+    match tokens.first() {
+        Some(TokenTree::Punct(punct)) if punct.as_char() == NEGATIVE_SYMBOL => Ok(SyntaxTreeNode::not_node(parse_not(&tokens[1..], punct.span(), chain)?)),
+        _ => parse_operand(tokens, span, chain),
+    }
+}
+
+/// Parse a parenthesized group, a leaf or an alias.
+fn parse_operand(tokens : &[TokenTree], span : Span, chain : &[String]) -> Result<Node, Vec<NSCFGSpanError>> {
+
+    let stream : TokenStream = tokens.iter().cloned().collect();
+    let (start, end) = tokens_span(tokens, span);
+
+    // 1. Verify that node isn't empty.
+    if tokens.is_empty() {
+        return Err(vec![NSCFGError::EmptyNode.at(&stream.to_string(), span)]);
+    }
+
+    // 2. Group between parenthesis
+    if let [TokenTree::Group(group)] = tokens {
+        if group.delimiter() == Delimiter::Parenthesis {
+            return parse_any(&group.stream().into_iter().collect::<Vec<TokenTree>>(), group.span(), chain);
+        }
+    }
+
+    // 2.1. Grouped values `(a | b):pred`
+    if let Some(group) = distribute_predicate(tokens) {
+        return parse_any(&group.stream().into_iter().collect::<Vec<TokenTree>>(), group.span(), chain);
+    }
+
+    // 2.2. Range leaves `>=32:pw`
+    if let Some(node) = parse_range(tokens, start, end) {
+        return node;
+    }
+
+    // 3. Raw leaves are passed through verbatim
+    if let Some(predicate) = raw_predicate(tokens) {
+        return Ok(Rc::new(SyntaxTreeNode::LEAF(stream.to_string(), predicate)));
+    }
+
+    // 3.1. Parametrized alias `name(arg, ...)`, arguments are glued like leaf values.
+    if let Some((name, args)) = alias_arguments(tokens) {
+        let content = stream.to_string();
+        let mut values : Vec<String> = Vec::new();
+        for arg in &args {
+            if arg.is_empty() {
+                return Err(vec![NSCFGError::EmptyNode.between(&content, start, end)]);
             }
-        } else {
-            content.extend(TokenStream::from(t));
+            values.push(glue_value(arg).map_err(|span| vec![NSCFGError::MissingOperator.at(&content, span)])?);
         }
+
+        // Errors inside alias are located on the alias in source.
+        return parse_alias(&name, &values, start, chain).map_err(|errors| errors.into_iter().map(|err| NSCFGSpanError { start, end, ..err }).collect());
     }
 
-    (symbol, content)
+    // 3.2. Validate leaf tokens
+    for t in tokens {
+        match t {
+            TokenTree::Punct(punct) => match punct.as_char() {
+                ':' | '_' | '-' | '.' => {},    // Valid leaf characters
+                NEGATIVE_SYMBOL => return Err(vec![NSCFGError::MissingOperator.at(&stream.to_string(), punct.span())]),
+                c => return Err(vec![NSCFGError::InvalidCharacter(String::from(c)).at(&stream.to_string(), punct.span())]),
+            },
+            TokenTree::Group(group) => return Err(vec![NSCFGError::MissingOperator.at(&stream.to_string(), group.span())]),
+            _ => {},
+        }
+    }
+
+    // 4. Create leaf or unwrap alias
+    let content = stream.to_string();
+    match tokens.iter().position(|t| matches!(t, TokenTree::Punct(punct) if punct.as_char() == ':')) {
+        Some(pos) => {    // End LEAF reached
+            // Value tokens must be written next to each other and predicate is a single token.
+            let label = glue_value(&tokens[..pos]).map_err(|span| vec![NSCFGError::MissingOperator.at(&content, span)])?;
+            let cfg_opt = match &tokens[pos + 1..] {
+                [] => String::new(),
+                [token] => token.to_string(),
+                [_, next, ..] => return Err(vec![NSCFGError::MissingOperator.at(&content, next.span())]),
+            };
+
+            match get_nscfg_leaf_predicate(unquote_value(&label), &cfg_opt) {
+                Ok(predicate) => Ok(Rc::new(SyntaxTreeNode::LEAF(format!("{}:{}", label, cfg_opt), predicate))),
+                Err(err) => Err(vec![err.between(&content, start, end)]),
+            }
+        },
+        None => {   // Unwrap alias
+            if content.contains(' ') {    // Make sure node doesn't contains spaces.
+                return Err(vec![NSCFGError::MissingOperator.between(&content, start, end)]);
+            }
+            // Errors inside alias are located on the alias in source.
+            parse_alias(&content, &[], start, chain).map_err(|errors| errors.into_iter().map(|err| NSCFGSpanError { start, end, ..err }).collect())
+        },
+    }
 
 }
 
-/// Verify if node is a NOT node, or not
-#[inline(always)]
-fn is_not_node(symbol : TokenStream) -> bool{
+/// Glue leaf value tokens written next to each other, like `my-feature`, `sse4.1` or `2d-render`.
+/// 
+/// Tokens parsed from alias content share the same span and are considered next to each other, since spaces of content
+/// written as string are verified by [has_spaced_value]. Two idents or literals are never next to each other, they would be a single token.
+/// 
+/// Error(s)
+/// Returns Err(Span) of the first token separated from the previous one.
+fn glue_value(tokens : &[TokenTree]) -> Result<String, Span> {
+    let mut value = String::new();
+
+    for (i, token) in tokens.iter().enumerate() {
+        if let Some(previous) = i.checked_sub(1).map(|i| &tokens[i]) {
+            let (previous_span, next) = (previous.span(), token.span());
+            let is_adjacent = (previous_span.end().line(), previous_span.end().column()) == (next.start().line(), next.start().column());
+            let is_same = (previous_span.start().line(), previous_span.start().column()) == (next.start().line(), next.start().column());
+            let is_word = |token : &TokenTree| matches!(token, TokenTree::Ident(_) | TokenTree::Literal(_));
+            if !is_adjacent && (!is_same || (is_word(previous) && is_word(token))) {
+                return Err(next);
+            }
+        }
+        value.push_str(&token.to_string());
+    }
 
-    for t in symbol {
-        match t {
-            proc_macro::TokenTree::Punct(punc) => {
-                match punc.as_char() {
-                    NEGATIVE_SYMBOL => return true,
+    Ok(value)
+}
+
+/// Verify if alias content written as string has a space inside a leaf value or after a range operator, like `lin ux:os`,
+/// `my -feature:ft` or `>= 32:pw`. Quoted values are skipped.
+/// 
+/// Tokens parsed from a string share the same span, so spaces between them can only be found in the string.
+fn has_spaced_value(content : &str) -> bool {
+    let is_value = |c : char| c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '"');
+    let mut previous : (Option<char>, Option<char>) = (None, None);     // Last two characters that aren't spaces
+    let (mut spaced, mut quoted, mut escaped) = (false, false, false);
+
+    for c in content.chars() {
+        if quoted {     // Skip quoted value until its closing quote
+            match (escaped, c) {
+                (true, _) => escaped = false,
+                (false, '\\') => escaped = true,
+                (false, '"') => quoted = false,
+                _ => {},
+            }
+        } else if c.is_whitespace() {
+            spaced = true;
+            continue;
+        } else {
+            if spaced && is_value(c) {
+                match previous {
+                    (_, Some(p)) if is_value(p) || p == '<' || p == '>' => return true,
+                    (Some('<' | '>'), Some('=')) => return true,
                     _ => {},
                 }
-            },
-            _ => {},
+            }
+            quoted = c == '"';
         }
+        previous = (previous.1, Some(c));
+        spaced = false;
     }
 
-    // Default value for documentation
     false
 }
 
-/// Split a token stream at specified token tree
+/// Remove quotes of a quoted value `"my feature"`. Other values are returned as is.
 #[inline(always)]
-pub(crate) fn split_tokenstream_at_operator(stream : TokenStream) -> Option<(char, TokenStream, TokenStream)> {
-
-    // Used to munch tokens on the left
-    let mut left = TokenStream::new();
-
-    // Used to munch tokens on the right
-    let mut right = TokenStream::new();
-
-    // Operator used to separate tokenstream
-    let mut operator: char = '?';
-
-
-    for t in stream.clone() {
-
-        match operator {
-            '?' => {
-                match t.clone() {
-                    proc_macro::TokenTree::Punct(symbol) => {
-                        match symbol.as_char() {
-                                AND_SYMBOL => {    // ALL node
-                                    operator = AND_SYMBOL
-                                }
-                                OR_SYMBOL => {    // ANY node
-                                    operator = OR_SYMBOL
-                                }
-                                // Valid ignored characters
-                                NEGATIVE_SYMBOL | '_' | '-' | ' ' | ':' | '.' => left.extend(TokenStream::from(t)),    // Munch tokens in left hand
-                                    
-                                _ => {
-                                    //err illegal
-                                    panic!("{}", NSCFGError::InvalidCharacter(String::from(symbol.as_char())).message(&stream.to_string()));
-                                },
-                            }
-                        },
-                    _ => left.extend(TokenStream::from(t)),    // Munch tokens in left hand
+fn unquote_value(value : &str) -> &str {
+    match value.strip_prefix('"').and_then(|value| value.strip_suffix('"')) {
+        Some(unquoted) if value.len() > 1 => unquoted,
+        _ => value,
+    }
+}
+
+/// Parse a range leaf like `>=32:pw` into `any()` of the leaves of each value of predicate domain in range.
+/// 
+/// Range tokens must be written next to each other. Bound must be a number and `ptr:at` is compared as the target pointer width, 
+/// thus `>=32:at` includes `all(ptr:at, any(32:pw, 64:pw))`.
+/// Returns None if tokens aren't a range leaf.
+/// 
+/// Error(s)
+/// Returns Some(Err(Vec<[NSCFGSpanError]>)) if tokens are separated, if predicate has no domain, if bound isn't a number of domain or if no value is in range.
+fn parse_range(tokens : &[TokenTree], start : Span, end : Span) -> Option<Result<Node, Vec<NSCFGSpanError>>> {
+
+    // 1. Get comparison operator
+    let operator : String = tokens.iter().map_while(|t| match t {
+        TokenTree::Punct(punct) if matches!(punct.as_char(), '<' | '>' | '=') => Some(punct.as_char()),
+        _ => None,
+    }).collect();
+    if !operator.starts_with(['<', '>']) {
+        return None;
+    }
+
+    // 2. Get bound and predicate written next to each other
+    let content = tokens.iter().cloned().collect::<TokenStream>().to_string();
+    let range = match glue_value(tokens) {
+        Ok(range) => range,
+        Err(span) => return Some(Err(vec![NSCFGError::MissingOperator.at(&content, span)])),
+    };
+    let (bound, predicate) = range[operator.len()..].split_once(':')?;
+
+    // 3. Get predicate domain and bounds accepted
+    let domain = match RANGE_DOMAINS.iter().find(|(key, _)| key.eq(&predicate)) {
+        Some((_, domain)) => *domain,
+        None => return Some(Err(vec![NSCFGError::RangeNotSupported(String::from(predicate)).between(&range, start, end)])),
+    };
+    let bounds : Vec<&str> = domain.iter().filter(|value| value.parse::<u32>().is_ok()).copied().collect();
+
+    // 4. Get values in range
+    let bound_value = match bound.parse::<u32>() {
+        Ok(bound_value) if bounds.contains(&bound) => bound_value,
+        _ => return Some(Err(vec![NSCFGError::RangeOutOfDomain(bounds).between(&range, start, end)])),
+    };
+    let in_range = |value : &str| value.parse::<u32>().is_ok_and(|value| match operator.as_str() {
+        ">=" => value >= bound_value,
+        ">" => value > bound_value,
+        "<=" => value <= bound_value,
+        "<" => value < bound_value,
+        _ => false,
+    });
+    let values : Vec<&str> = domain.iter().filter(|value| in_range(value)).copied().collect();
+    let pointer_widths : Vec<&str> = RANGE_DOMAINS.iter().filter(|(key, _)| key.eq(&RANGE_POINTER_PREDICATE))
+        .flat_map(|(_, domain)| domain.iter()).filter(|value| in_range(value)).copied().collect();
+
+    if values.is_empty() {
+        return Some(Err(vec![NSCFGError::RangeOutOfDomain(bounds).between(&range, start, end)]));
+    }
+
+    // 5. Generate leaf of each value
+    let leaf = |value : &str, predicate : &str| {
+        let leaf = format!("{}:{}", value, predicate);
+        get_nscfg_predicate(&leaf).map(|cfg| Rc::new(SyntaxTreeNode::LEAF(leaf, cfg))).map_err(|err| vec![err.between(&range, start, end)])
+    };
+    let mut leaves : Vec<Node> = Vec::new();
+    for value in values {
+        match leaf(value, predicate) {
+            Ok(node) => leaves.push(node),
+            Err(err) => return Some(Err(err)),
+        }
+    }
+
+    // 5.1. Pointer value is in range if target pointer width is
+    if domain.contains(&RANGE_POINTER_VALUE) && !pointer_widths.is_empty() {
+        let widths : Result<Vec<Node>, Vec<NSCFGSpanError>> = pointer_widths.into_iter().map(|value| leaf(value, RANGE_POINTER_PREDICATE)).collect();
+        match (leaf(RANGE_POINTER_VALUE, predicate), widths) {
+            (Ok(pointer), Ok(widths)) => leaves.push(SyntaxTreeNode::all_node(vec![pointer, SyntaxTreeNode::any_node(widths)])),
+            (Err(err), _) | (_, Err(err)) => return Some(Err(err)),
+        }
+    }
+
+    Some(Ok(SyntaxTreeNode::any_node(leaves)))
+
+}
+
+/// Get grouped values `(a | b):pred` as a group with the predicate written after each value, like `(a:pred | b:pred)`.
+/// 
+/// Returns None if tokens aren't grouped values.
+fn distribute_predicate(tokens : &[TokenTree]) -> Option<Group> {
+    match tokens {
+        [TokenTree::Group(group), TokenTree::Punct(colon), predicate @ ..] if group.delimiter() == Delimiter::Parenthesis && colon.as_char() == ':' && !predicate.is_empty() => {
+            let suffix : Vec<TokenTree> = tokens[1..].to_vec();
+            let mut distributed = Group::new(Delimiter::Parenthesis, add_predicate(group.stream(), &suffix));
+            distributed.set_span(group.span());
+            Some(distributed)
+        },
+        _ => None,
+    }
+}
+
+/// Write predicate suffix `:pred` after each value of stream, including values of nested groups.
+fn add_predicate(stream : TokenStream, suffix : &[TokenTree]) -> TokenStream {
+    let mut tokens : Vec<TokenTree> = Vec::new();
+    let mut is_value = false;   // True while value tokens are written
+
+    for token in stream {
+        match &token {
+            TokenTree::Punct(punct) if punct.as_char() == AND_SYMBOL || punct.as_char() == OR_SYMBOL || punct.as_char() == NEGATIVE_SYMBOL => {
+                if is_value {
+                    tokens.extend(suffix.iter().cloned());
+                    is_value = false;
                 }
+                tokens.push(token);
+            },
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Parenthesis && !is_value => {
+                let mut nested = Group::new(Delimiter::Parenthesis, add_predicate(group.stream(), suffix));
+                nested.set_span(group.span());
+                tokens.push(TokenTree::from(nested));
             },
-            _ => right.extend(TokenStream::from(t)),    // Munch tokens in right hand
-        }        
+            _ => {
+                tokens.push(token);
+                is_value = true;
+            },
+        }
     }
 
-    match operator {
-        '?' => None,    // No split happened
-        _ => Some((operator, left, right)), // Split happened
+    if is_value {
+        tokens.extend(suffix.iter().cloned());
+    }
+
+    tokens.into_iter().collect()
+}
 
+/// Get the predicate of raw leaves `cfg(predicate)`, `key = "value"` and `key="value":kv`, written as is.
+/// 
+/// Returns None if tokens aren't a raw leaf.
+fn raw_predicate(tokens : &[TokenTree]) -> Option<String> {
+    match tokens {
+        [TokenTree::Ident(ident), TokenTree::Group(group)] if ident.to_string().eq(RAW_CFG_KEYWORD) && group.delimiter() == Delimiter::Parenthesis => Some(group.stream().to_string()),
+        [TokenTree::Ident(_), TokenTree::Punct(punct), TokenTree::Literal(_)] if punct.as_char() == KEY_VALUE_SYMBOL => Some(tokens.iter().cloned().collect::<TokenStream>().to_string()),
+        [key_value @ .., TokenTree::Punct(punct), TokenTree::Ident(ident)] if punct.as_char() == ':' && ident.to_string().eq(RAW_KV_PREDICATE) && key_value.len() == 3 => raw_predicate(key_value),
+        _ => None,
     }
+}
 
+/// Get the name and arguments tokens of a parametrized alias `name(arg, ...)`.
+/// 
+/// Returns None if tokens aren't a parametrized alias.
+fn alias_arguments(tokens : &[TokenTree]) -> Option<(String, Vec<Vec<TokenTree>>)> {
+    match tokens {
+        [TokenTree::Ident(ident), TokenTree::Group(group)] if group.delimiter() == Delimiter::Parenthesis => {
+            let args : Vec<TokenTree> = group.stream().into_iter().collect();
+            if args.is_empty() {
+                Some((ident.to_string(), Vec::new()))
+            } else {
+                Some((ident.to_string(), args.split(|t| matches!(t, TokenTree::Punct(punct) if punct.as_char() == ARGUMENT_SEPARATOR)).map(|arg| arg.to_vec()).collect()))
+            }
+        },
+        _ => None,
+    }
 }
 
+/// Expand an alias with its arguments, keeping the chain of aliases being expanded to detect cycles.
+/// 
+/// Error(s)
+/// Returns Err(Vec<[NSCFGSpanError]>) if alias isn't found, has a different count of parameters, creates a cycle, exceeds maximum depth,
+/// can't be tokenized or has errors.
+fn parse_alias(label : &str, args : &[String], span : Span, chain : &[String]) -> Result<Node, Vec<NSCFGSpanError>> {
+
+    // 1. Verify that alias isn't already being expanded
+    if let Some(pos) = chain.iter().position(|alias| alias.eq(label)) {
+        let mut cycle = chain[pos..].to_vec();
+        cycle.push(String::from(label));
+        return Err(vec![NSCFGError::AliasCycle(cycle).at(label, span)]);
+    }
+
+    // 2. Add alias to chain
+    let mut chain = chain.to_vec();
+    chain.push(String::from(label));
+
+    // 3. Verify expansion depth
+    let max_depth = get_max_alias_depth();
+    if chain.len() > max_depth {
+        return Err(vec![NSCFGError::AliasDepthExceeded(max_depth, chain).at(label, span)]);
+    }
 
-/// Split tokenstream in different [item](https://doc.rust-lang.org/reference/items.html) vector tokenstream.
+    // 4. Parse alias content. Content written as string must not have spaces inside values, which tokens lose.
+    match get_nscfg_alias(label, args) {
+        Ok((alias, source)) if !matches!(source, DefinitionSource::Source) && has_spaced_value(&alias) => {
+            Err(vec![NSCFGError::MissingOperator.at(&alias, span)])
+        },
+        Ok((alias, _)) => match alias.parse::<TokenStream>() {
+            Ok(stream) => parse_any(&stream.into_iter().collect::<Vec<TokenTree>>(), span, &chain),
+            Err(_) => Err(vec![NSCFGError::AliasContentError(String::from(label)).at(&alias, span)]),
+        },
+        Err(err) => Err(vec![err.at(label, span)]),
+    }
+
+}
+
+/// Generate a SyntaxTreeNode the way it was done before operator precedence. 
 /// 
-/// An item is defined as all tokens until a ; and/or {}.
-#[inline(always)]
-pub(crate) fn split_items(stream : TokenStream) -> Vec<TokenStream> {
-
-    let mut item = TokenStream::new();
-    let mut items : Vec<TokenStream> = Vec::new();
-
-    for t in stream {
-        match &t {
-            proc_macro::TokenTree::Group(grp) => {
-                // Validate if first and last character of group is 
-                match grp.delimiter(){
-                    proc_macro::Delimiter::Brace => {    // End of item. 
-                        item.extend(TokenStream::from(t)); // Add into item tokenstream
-                        items.push(item);   // Push item into vector.
-                        item = TokenStream::new();  // Reset item tokenstream
-                    },
-                    _ => item.extend(TokenStream::from(t)), // Add into item tokenstream
-                }
+/// The stream was split at the first `&` or `|` found and any amount of `!` meant a single NOT node.
+/// Only used to detect arms that changed meaning. Returns None instead of panicking on error.
+fn generate_without_precedence(stream : TokenStream, chain : &[String]) -> Option<Node> {
+
+    let tokens : Vec<TokenTree> = stream.into_iter().collect();
+
+    // 1. Split at first operator
+    if let Some(pos) = tokens.iter().position(|t| matches!(t, TokenTree::Punct(punct) if punct.as_char() == AND_SYMBOL || punct.as_char() == OR_SYMBOL)) {
+        let left = generate_without_precedence(tokens[..pos].iter().cloned().collect(), chain)?;
+        let right = generate_without_precedence(tokens[pos + 1..].iter().cloned().collect(), chain)?;
+
+        return match &tokens[pos] {
+            TokenTree::Punct(punct) if punct.as_char() == AND_SYMBOL => Some(SyntaxTreeNode::all_node(vec![left, right])),
+            _ => Some(SyntaxTreeNode::any_node(vec![left, right])),
+        };
+    }
+
+    // 2. Any amount of ! is a single NOT node
+    let negative = tokens.iter().take_while(|t| matches!(t, TokenTree::Punct(punct) if punct.as_char() == NEGATIVE_SYMBOL)).count();
+    if negative > 0 {
+        return Some(SyntaxTreeNode::not_node(generate_without_precedence(tokens[negative..].iter().cloned().collect(), chain)?));
+    }
+
+    // 3. Grouped values, range, raw leaf, group, leaf or alias
+    if let Some(group) = distribute_predicate(&tokens) {
+        return generate_without_precedence(group.stream(), chain);
+    }
+    if let Some(node) = parse_range(&tokens, Span::call_site(), Span::call_site()) {
+        return node.ok();
+    }
+    if let Some(predicate) = raw_predicate(&tokens) {
+        return Some(Rc::new(SyntaxTreeNode::LEAF(tokens.iter().cloned().collect::<TokenStream>().to_string(), predicate)));
+    }
+    let (content, args) = match alias_arguments(&tokens) {
+        Some((name, args)) => (name, args.iter().map(|arg| arg.iter().map(|t| t.to_string()).collect::<String>()).collect()),
+        None => {
+            if let Some(TokenTree::Group(group)) = tokens.iter().find(|t| matches!(t, TokenTree::Group(_))) {
+                return generate_without_precedence(group.stream(), chain);
             }
-            ,
-            proc_macro::TokenTree::Punct(punc) => {
-                if punc.as_char().eq(&';') { // End of item.
-                    item.extend(TokenStream::from(t)); // Add into item tokenstream
-                    items.push(item);   // Push item into vector.
-                    item = TokenStream::new();  // Reset item tokenstream
-                } else {
-                    item.extend(TokenStream::from(t)); // Add into item tokenstream
-                }
-            },
-            _ => item.extend(TokenStream::from(t)), // Add into item tokenstream
-        }
+            (tokens.iter().cloned().collect::<TokenStream>().to_string(), Vec::new())
+        },
+    };
+
+    if content.is_empty() {
+        None
+    } else if content.contains(':') {
+        Some(Rc::new(SyntaxTreeNode::LEAF(content, String::new())))
+    } else if chain.contains(&content) || chain.len() >= get_max_alias_depth() {
+        None    // Alias cycle, reported by generate
+    } else {
+        let mut chain = chain.to_vec();
+        chain.push(content.clone());
+        generate_without_precedence(get_nscfg_alias(&content, &args).ok()?.0.parse().ok()?, &chain)
     }
 
-    items
-}
\ No newline at end of file
+}
@@ -0,0 +1,222 @@
+// Configuration of a target deduced from its triple, used to verify arms coverage of supported targets.
+//
+// Only keys decided by the target are known. Other keys like `feature` or `debug_assertions` can't be decided.
+
+#[cfg(test)]
+#[path = "../tests/unit/target.rs"]
+mod unit_tests; // Unit tests located in tests folder
+
+/// Keys decided by the target triple. `doc` is never set when compiling for a target.
+const TARGET_KEYS : [&str; 11] = ["target_arch", "target_os", "target_family", "target_env", "target_abi", "target_vendor",
+    "target_pointer_width", "target_endian", "unix", "windows", "doc"];
+
+/// Vendors that can be written after architecture in triples.
+const TARGET_VENDORS : [&str; 8] = ["unknown", "pc", "apple", "nvidia", "sun", "wrs", "fortanix", "uwp"];
+
+/// Operating systems of the unix family.
+const UNIX_OS : [&str; 17] = ["linux", "android", "macos", "ios", "tvos", "watchos", "visionos", "freebsd", "netbsd", "openbsd",
+    "dragonfly", "solaris", "illumos", "haiku", "fuchsia", "redox", "aix"];
+
+/// Operating systems outside of the unix family.
+const OTHER_OS : [&str; 10] = ["windows", "none", "wasi", "uefi", "emscripten", "hermit", "espidf", "vxworks", "nto", "horizon"];
+
+/// Known architectures.
+const TARGET_ARCHS : [&str; 24] = ["x86", "x86_64", "arm", "aarch64", "riscv32", "riscv64", "wasm32", "wasm64", "mips", "mips64", "powerpc",
+    "powerpc64", "s390x", "loongarch64", "sparc", "sparc64", "avr", "msp430", "bpf", "nvptx64", "m68k", "csky", "hexagon", "xtensa"];
+
+/// Keys that have a single value for a target.
+const SINGLE_VALUE_KEYS : [&str; 7] = ["target_arch", "target_os", "target_env", "target_abi", "target_vendor", "target_pointer_width", "target_endian"];
+
+/// Configuration key and value (None for keys without value like `unix`) of a target.
+pub(crate) struct TargetCfg {
+    pub triple : String,                        // Target triple
+    cfg : Vec<(String, Option<String>)>,        // Configuration set for target
+}
+
+impl TargetCfg {
+    /// Deduce the configuration of a target from its triple (`arch-vendor-os-env`).
+    pub fn from_triple(triple : &str) -> TargetCfg {
+
+        let parts : Vec<&str> = triple.split('-').collect();
+
+        // 1. Architecture
+        let arch = Self::get_arch(parts[0]);
+
+        // 2. Vendor is optional (`aarch64-linux-android`)
+        let (vendor, rest) = match parts.get(1) {
+            Some(vendor) if TARGET_VENDORS.contains(vendor) => (*vendor, &parts[2.min(parts.len())..]),
+            _ => ("unknown", &parts[1.min(parts.len())..]),
+        };
+
+        // 3. Operating system, environment and abi
+        let mut os = String::from(rest.first().copied().unwrap_or("none"));
+        let (mut env, abi) = Self::get_env_abi(&rest.get(1..).unwrap_or_default().join("-"));
+        match (os.as_str(), env.as_str()) {
+            ("darwin", _) => os = String::from("macos"),
+            ("wasip1" | "wasip2", _) => os = String::from("wasi"),
+            ("linux", "android") => {  // Android is written as environment of linux
+                os = String::from("android");
+                env = String::new();
+            },
+            _ => {},
+        }
+
+        // 4. Family
+        let mut families : Vec<&str> = Vec::new();
+        if UNIX_OS.contains(&os.as_str()) || os.eq("emscripten") {
+            families.push("unix");
+        }
+        if os.eq("windows") {
+            families.push("windows");
+        }
+        if arch.starts_with("wasm") {
+            families.push("wasm");
+        }
+
+        // 5. Generate configuration
+        let mut cfg : Vec<(String, Option<String>)> = vec![
+            (String::from("target_pointer_width"), Some(String::from(Self::get_pointer_width(&arch)))),
+            (String::from("target_endian"), Some(String::from(Self::get_endian(parts[0])))),
+            (String::from("target_vendor"), Some(String::from(vendor))),
+            (String::from("target_env"), Some(env)),
+            (String::from("target_abi"), Some(abi)),
+            (String::from("target_arch"), Some(arch)),
+            (String::from("target_os"), Some(os)),
+        ];
+        for family in families {
+            cfg.push((String::from("target_family"), Some(String::from(family))));
+            if family.ne("wasm") {
+                cfg.push((String::from(family), None));
+            }
+        }
+
+        TargetCfg { triple : String::from(triple), cfg }
+
+    }
+
+    /// Decide if key with value is set for target.
+    ///
+    /// Returns None if key isn't decided by target.
+    pub fn evaluate(&self, key : &str, value : Option<&str>) -> Option<bool> {
+        if TARGET_KEYS.contains(&key) {
+            Some(self.cfg.iter().any(|(k, v)| k.eq(key) && v.as_deref().eq(&value)))
+        } else {
+            None
+        }
+    }
+
+    /// Get `target_arch` from triple architecture.
+    fn get_arch(arch : &str) -> String {
+        String::from(match arch {
+            "i386" | "i586" | "i686" => "x86",
+            "arm64" | "aarch64_be" => "aarch64",
+            "powerpc64le" => "powerpc64",
+            "mipsel" => "mips",
+            "mips64el" => "mips64",
+            "sparcv9" => "sparc64",
+            _ if arch.starts_with("arm") || arch.starts_with("thumb") => "arm",
+            _ if arch.starts_with("riscv64") => "riscv64",
+            _ if arch.starts_with("riscv32") => "riscv32",
+            _ => arch,
+        })
+    }
+
+    /// Get `target_env` and `target_abi` from the end of triple.
+    fn get_env_abi(env : &str) -> (String, String) {
+        for prefix in ["gnu", "musl", "uclibc", "msvc", "sgx", "ohos", "newlib", "android"] {
+            if let Some(abi) = env.strip_prefix(prefix) {
+                return (String::from(prefix), String::from(abi));
+            }
+        }
+
+        (String::new(), String::from(env))     // `sim`, `macabi`, `eabihf`, ...
+    }
+
+    /// Get `target_pointer_width` of architecture.
+    fn get_pointer_width(arch : &str) -> &'static str {
+        match arch {
+            "avr" | "msp430" => "16",
+            "x86_64" | "aarch64" | "riscv64" | "powerpc64" | "mips64" | "s390x" | "loongarch64" | "sparc64" | "wasm64" | "bpf" | "nvptx64" => "64",
+            _ => "32",
+        }
+    }
+
+    /// Get `target_endian` from triple architecture.
+    fn get_endian(arch : &str) -> &'static str {
+        match arch {
+            "powerpc" | "powerpc64" | "s390x" | "sparc" | "sparc64" | "sparcv9" | "mips" | "mips64" | "aarch64_be" | "armeb" | "armebv7r" | "m68k" => "big",
+            _ => "little",
+        }
+    }
+}
+
+/// Get known values of a key that has a single value for a target. Empty if values aren't known.
+fn get_key_domain(key : &str) -> Vec<&'static str> {
+    match key {
+        "target_os" => UNIX_OS.iter().chain(OTHER_OS.iter()).copied().collect(),
+        "target_arch" => TARGET_ARCHS.to_vec(),
+        "target_pointer_width" => vec!["16", "32", "64"],
+        "target_endian" => vec!["little", "big"],
+        _ => Vec::new(),
+    }
+}
+
+/// Split every configuration according to leaves predicates (`key` or `key = "value"`), used to name a configuration in messages.
+/// 
+/// Keys with a single value for a target like `target_os` are split into the values of leaves, then into their known values while 
+/// there are at most `max` configurations, and a last configuration for any other value. Other leaves are either set or not.
+/// Returns configurations as (predicate, description) or None if there are more than `max`.
+pub(crate) fn split_configurations(leaves : &[String], max : usize) -> Option<Vec<(String, String)>> {
+
+    // 1. Group values of single value keys. Other leaves are their own key.
+    let mut keys : Vec<(String, Vec<String>)> = Vec::new();
+    for leaf in leaves {
+        let (key, value) = match leaf.split_once('=') {
+            Some((key, value)) if SINGLE_VALUE_KEYS.contains(&key.trim()) => (key.trim(), value.trim().trim_matches('"')),
+            _ => (leaf.as_str(), ""),
+        };
+        match keys.iter_mut().find(|(k, _)| k.eq(key)) {
+            Some((_, values)) => if !values.iter().any(|v| v.eq(value)) {
+                values.push(String::from(value));
+            },
+            None => keys.push((String::from(key), vec![String::from(value)])),
+        }
+    }
+
+    // 2. Count configurations. A last one is added to each key for other values or leaf not set.
+    let mut count : usize = 1;
+    for (_, values) in keys.iter() {
+        count = count.checked_mul(values.len() + 1).filter(|count| *count <= max)?;
+    }
+
+    // 3. Add known values of keys while count allows it
+    let mut splits : Vec<Vec<(String, String)>> = Vec::new();
+    for (key, mut values) in keys {
+        if values.iter().all(|value| value.is_empty()) {    // Leaf is set or not
+            splits.push(vec![(key.clone(), key.clone()), (format!("not({})", key), format!("not({})", key))]);
+            continue;
+        }
+
+        let written : Vec<String> = values.iter().map(|value| format!("{} = {:?}", key, value)).collect();
+        let domain : Vec<&str> = get_key_domain(&key).into_iter().filter(|value| !values.iter().any(|v| v.eq(value))).collect();
+        if count / (values.len() + 1) * (values.len() + domain.len() + 1) <= max {
+            count = count / (values.len() + 1) * (values.len() + domain.len() + 1);
+            values.extend(domain.into_iter().map(String::from));
+        }
+
+        let mut split : Vec<(String, String)> = values.iter().map(|value| (format!("{} = {:?}", key, value), format!("{} = {:?}", key, value))).collect();
+        split.push((format!("not(any({}))", split.iter().map(|(pred, _)| pred.as_str()).collect::<Vec<&str>>().join(", ")), format!("not(any({}))", written.join(", "))));
+        splits.push(split);
+    }
+
+    // 4. Combine each split of keys
+    let mut configurations : Vec<(Vec<String>, Vec<String>)> = vec![(Vec::new(), Vec::new())];
+    for split in splits {
+        configurations = configurations.into_iter().flat_map(|(preds, descs)| split.iter().map(move |(pred, desc)| {
+            ([preds.clone(), vec![pred.clone()]].concat(), [descs.clone(), vec![desc.clone()]].concat())
+        })).collect();
+    }
+
+    Some(configurations.into_iter().map(|(preds, descs)| (format!("all({})", preds.join(", ")), descs.join(", "))).collect())
+
+}
@@ -0,0 +1,355 @@
+// Minimal TOML parser used to read nscfg configuration without dependencies.
+//
+// Supports tables, dotted and quoted keys, basic / literal strings (single and multi-line), booleans,
+// arrays and inline tables. Integers, floats and dates are kept as written.
+
+#[cfg(test)]
+#[path = "../tests/unit/toml.rs"]
+mod unit_tests; // Unit tests located in tests folder
+
+/// TOML value.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum TomlValue {
+    /// A string value
+    String(String),
+
+    /// A boolean value
+    Boolean(bool),
+
+    /// An array of values
+    Array(Vec<TomlValue>),
+
+    /// An inline table
+    Table(TomlEntries),
+
+    /// Integers, floats and dates as written
+    Other(String),
+}
+
+/// Key path and value of each TOML entry.
+pub(crate) type TomlEntries = Vec<(Vec<String>, TomlValue)>;
+
+/// Parse TOML content into a vector of entries. Inline tables outside arrays are flattened into their keys.
+/// Table headers and empty inline tables are kept as an empty [TomlValue::Table] entry so their existence can be verified.
+///
+/// Error(s)
+/// Returns Err(String) with line number and reason if content isn't valid.
+pub(crate) fn parse_toml(content : &str) -> Result<TomlEntries, String> {
+    TomlParser { chars : content.chars().collect(), pos : 0, line : 1 }.parse()
+}
+
+/// Get the entries of a table, with their key relative to the table. Empty table entries are skipped.
+pub(crate) fn get_toml_table<'a>(entries : &'a TomlEntries, table : &[&str]) -> Vec<(&'a [String], &'a TomlValue)> {
+    entries.iter().filter(|(path, value)| path.len() > table.len() && !matches!(value, TomlValue::Table(_)) && path.iter().zip(table).all(|(key, name)| key.eq(name)))
+        .map(|(path, value)| (&path[table.len()..], value)).collect()
+}
+
+/// Returns true if table is defined, either by a header or by a key.
+pub(crate) fn has_toml_table(entries : &TomlEntries, table : &[&str]) -> bool {
+    entries.iter().any(|(path, _)| path.len() >= table.len() && path.iter().zip(table).all(|(key, name)| key.eq(name)))
+}
+
+/// Get a single value from its full key path.
+pub(crate) fn get_toml_value<'a>(entries : &'a TomlEntries, key : &[&str]) -> Option<&'a TomlValue> {
+    entries.iter().rev().find(|(path, _)| path.len() == key.len() && path.iter().zip(key).all(|(key, name)| key.eq(name))).map(|(_, value)| value)
+}
+
+/// Parser state.
+struct TomlParser {
+    chars : Vec<char>,  // Content characters
+    pos : usize,        // Current position
+    line : usize,       // Current line used for errors
+}
+
+impl TomlParser {
+    /// Parse the whole content.
+    fn parse(&mut self) -> Result<TomlEntries, String> {
+
+        let mut entries : TomlEntries = Vec::new();
+
+        // Current table header
+        let mut table : Vec<String> = Vec::new();
+
+        loop {
+            self.skip_blank(true);
+
+            match self.peek() {
+                None => break,
+                Some('[') => {  // Table header or array of tables header
+                    self.pos += 1;
+                    let is_array = self.eat('[');
+                    self.skip_blank(false);
+                    table = self.parse_key()?;
+                    self.skip_blank(false);
+                    if !self.eat(']') || (is_array && !self.eat(']')) {
+                        return Err(self.error("`]` expected after table name"));
+                    }
+                    entries.push((table.clone(), TomlValue::Table(Vec::new())));
+                },
+                Some(_) => {    // key = value
+                    let key = self.parse_key()?;
+                    self.skip_blank(false);
+                    if !self.eat('=') {
+                        return Err(self.error("`=` expected after key"));
+                    }
+                    self.skip_blank(false);
+                    let value = self.parse_value()?;
+
+                    let mut path = table.clone();
+                    path.extend(key);
+                    Self::push_entry(&mut entries, path, value);
+                },
+            }
+
+            // Only a comment can follow on the same line
+            self.skip_blank(false);
+            match self.peek() {
+                None | Some('\n') => {},
+                Some(c) => return Err(self.error(&format!("unexpected character `{}`", c))),
+            }
+        }
+
+        Ok(entries)
+
+    }
+
+    /// Push an entry, flattening inline tables.
+    fn push_entry(entries : &mut TomlEntries, path : Vec<String>, value : TomlValue) {
+        match value {
+            TomlValue::Table(table) if !table.is_empty() => {
+                for (key, value) in table {
+                    let mut sub_path = path.clone();
+                    sub_path.extend(key);
+                    Self::push_entry(entries, sub_path, value);
+                }
+            },
+            _ => entries.push((path, value)),
+        }
+    }
+
+    /// Peek current character.
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    /// Returns true if content at position starts with pattern.
+    fn starts_with(&self, pattern : &str) -> bool {
+        pattern.chars().enumerate().all(|(i, c)| self.chars.get(self.pos + i) == Some(&c))
+    }
+
+    /// Consume character if it is the one expected.
+    fn eat(&mut self, c : char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consume next character, counting lines.
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    /// Skip spaces and comments. Also skip new lines if asked.
+    fn skip_blank(&mut self, new_lines : bool) {
+        while let Some(c) = self.peek() {
+            match c {
+                ' ' | '\t' | '\r' => { self.pos += 1; },
+                '\n' if new_lines => { self.next(); },
+                '#' => {
+                    while !matches!(self.peek(), None | Some('\n')) {
+                        self.pos += 1;
+                    }
+                },
+                _ => break,
+            }
+        }
+    }
+
+    /// Create an error message with line number.
+    fn error(&self, reason : &str) -> String {
+        format!("line {} : {}", self.line, reason)
+    }
+
+    /// Parse a dotted key made of bare or quoted keys.
+    fn parse_key(&mut self) -> Result<Vec<String>, String> {
+        let mut key : Vec<String> = Vec::new();
+
+        loop {
+            self.skip_blank(false);
+            match self.peek() {
+                Some('"') | Some('\'') => match self.parse_value()? {
+                    TomlValue::String(part) => key.push(part),
+                    _ => return Err(self.error("invalid quoted key")),
+                },
+                _ => {
+                    let start = self.pos;
+                    while matches!(self.peek(), Some(c) if c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+                        self.pos += 1;
+                    }
+                    if start == self.pos {
+                        return Err(self.error("key expected"));
+                    }
+                    key.push(self.chars[start..self.pos].iter().collect());
+                },
+            }
+
+            self.skip_blank(false);
+            if !self.eat('.') {
+                return Ok(key);
+            }
+        }
+    }
+
+    /// Parse a value.
+    fn parse_value(&mut self) -> Result<TomlValue, String> {
+        if self.starts_with("\"\"\"") {
+            self.pos += 3;
+            self.parse_string("\"\"\"", true)
+        } else if self.starts_with("'''") {
+            self.pos += 3;
+            self.parse_string("'''", false)
+        } else if self.eat('"') {
+            self.parse_string("\"", true)
+        } else if self.eat('\'') {
+            self.parse_string("'", false)
+        } else if self.eat('[') {
+            self.parse_array()
+        } else if self.eat('{') {
+            self.parse_inline_table()
+        } else {
+            self.parse_bare()
+        }
+    }
+
+    /// Parse a string until delimiter. Basic strings handle escapes.
+    fn parse_string(&mut self, delimiter : &str, is_basic : bool) -> Result<TomlValue, String> {
+        let mut value = String::new();
+        let is_multiline = delimiter.len() == 3;
+
+        // A new line right after opening delimiter is trimmed.
+        if is_multiline {
+            self.eat('\r');
+            if self.peek() == Some('\n') {
+                self.next();
+            }
+        }
+
+        loop {
+            if self.starts_with(delimiter) {
+                self.pos += delimiter.len();
+                return Ok(TomlValue::String(value));
+            }
+
+            match self.next() {
+                None => return Err(self.error("unterminated string")),
+                Some('\n') if !is_multiline => return Err(self.error("unterminated string")),
+                Some('\\') if is_basic => match self.next() {
+                    Some('n') => value.push('\n'),
+                    Some('t') => value.push('\t'),
+                    Some('r') => value.push('\r'),
+                    Some('b') => value.push('\u{8}'),
+                    Some('f') => value.push('\u{c}'),
+                    Some('"') => value.push('"'),
+                    Some('\\') => value.push('\\'),
+                    Some(c) if c == 'u' || c == 'U' => {
+                        let len = if c == 'u' { 4 } else { 8 };
+                        let code : String = (0..len).filter_map(|_| self.next()).collect();
+                        match u32::from_str_radix(&code, 16).ok().and_then(char::from_u32) {
+                            Some(c) => value.push(c),
+                            None => return Err(self.error("invalid unicode escape")),
+                        }
+                    },
+                    Some(c) if is_multiline && c.is_whitespace() => {   // Line ending backslash trims whitespaces
+                        while matches!(self.peek(), Some(c) if c.is_whitespace()) {
+                            self.next();
+                        }
+                    },
+                    _ => return Err(self.error("invalid escape sequence")),
+                },
+                Some(c) => value.push(c),
+            }
+        }
+    }
+
+    /// Parse an array after `[`.
+    fn parse_array(&mut self) -> Result<TomlValue, String> {
+        let mut values : Vec<TomlValue> = Vec::new();
+
+        loop {
+            self.skip_blank(true);
+            if self.eat(']') {
+                return Ok(TomlValue::Array(values));
+            }
+
+            values.push(self.parse_value()?);
+
+            self.skip_blank(true);
+            if !self.eat(',') {
+                self.skip_blank(true);
+                if self.eat(']') {
+                    return Ok(TomlValue::Array(values));
+                }
+                return Err(self.error("`,` or `]` expected in array"));
+            }
+        }
+    }
+
+    /// Parse an inline table after `{`.
+    fn parse_inline_table(&mut self) -> Result<TomlValue, String> {
+        let mut entries : TomlEntries = Vec::new();
+
+        loop {
+            self.skip_blank(false);
+            if self.eat('}') {
+                return Ok(TomlValue::Table(entries));
+            }
+
+            let key = self.parse_key()?;
+            self.skip_blank(false);
+            if !self.eat('=') {
+                return Err(self.error("`=` expected after key"));
+            }
+            self.skip_blank(false);
+            let value = self.parse_value()?;
+            entries.push((key, value));
+
+            self.skip_blank(false);
+            if !self.eat(',') {
+                self.skip_blank(false);
+                if self.eat('}') {
+                    return Ok(TomlValue::Table(entries));
+                }
+                return Err(self.error("`,` or `}` expected in inline table"));
+            }
+        }
+    }
+
+    /// Parse booleans, numbers and dates.
+    fn parse_bare(&mut self) -> Result<TomlValue, String> {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if !matches!(c, ',' | ']' | '}' | '#' | '\t' | '\n' | '\r') && (c != ' ' || self.is_date_time_separator())) {
+            self.pos += 1;
+        }
+
+        let value : String = self.chars[start..self.pos].iter().collect();
+        match value.as_str() {
+            "" => Err(self.error("value expected")),
+            "true" => Ok(TomlValue::Boolean(true)),
+            "false" => Ok(TomlValue::Boolean(false)),
+            _ => Ok(TomlValue::Other(value)),
+        }
+    }
+
+    /// Returns true if space at position separates a date from a time (`1979-05-27 07:32:00`).
+    fn is_date_time_separator(&self) -> bool {
+        self.pos >= 3 && self.chars[self.pos - 3] == '-' && matches!(self.chars.get(self.pos + 3), Some(':'))
+    }
+}
@@ -143,7 +143,7 @@ run_test 005.rs "Invalid character"
 run_test 006.rs "Test 006 completed!"
 
 # T7~T8 NSCFGError::AliasNotFound error.
-run_test 007.rs "has no match! Is it added in config.toml"
+run_test 007.rs "cannot find macro \`nscfg_alias_desktap\`"
 run_test 008.rs "Test 008 completed!"
 
 # T9~T10 NSCFGError::InvalidConfigurationPredicate error.
@@ -190,7 +190,7 @@ run_test 024.rs "Test 024 completed!"
 
 
 # T25 Custom aliases missing
-run_test 025.rs "has no match! Is it added in config.toml"
+run_test 025.rs "cannot find macro \`nscfg_alias_pig\`"
 
 # Copy aliases
 cp -r "../tests/rs/alias.toml" ".cargo/config.toml" 
@@ -341,6 +341,260 @@ run_test 061.rs "Test 061 completed!"
 run_test 062.rs "Macro panicked because some arm have the"
 run_test 063.rs "Test 063 completed!"
 
+#T64-T65 Operator precedence
+run_test 064.rs "Test 064 completed!"
+run_test 065.rs "changed meaning since"
+
+#T66 Error span located on faulty token
+run_test 066.rs "src/main.rs:5:13"
+
+#T67 All errors reported at once
+run_test 067.rs "due to 3 previous errors"
+
+#T68 Alias near one defined in env is left to cfg_alias! helpers
+run_test 068.rs "cannot find macro \`nscfg_alias_frogg\`"
+
+#T69 Aliases and predicates from Cargo.toml [package.metadata.nscfg]
+echo "[package.metadata.nscfg.aliases]" >> Cargo.toml
+echo "cargo_desktop = \"linux:os | windows:os | macos:os\"" >> Cargo.toml
+echo "[package.metadata.nscfg.predicates]" >> Cargo.toml
+echo "width = 'target_pointer_width = \"{}\"'" >> Cargo.toml
+run_test 069.rs "Test 069 completed!"
+
+#T70 Aliases inherited from Cargo.toml [workspace.metadata.nscfg]
+echo "[workspace]" >> Cargo.toml
+echo "[workspace.metadata.nscfg.aliases]" >> Cargo.toml
+echo "workspace_desktop = \"linux:os | windows:os | macos:os\"" >> Cargo.toml
+echo "cargo_desktop = \"foo:os\"" >> Cargo.toml
+run_test 070.rs "Test 070 completed!"
+
+#T71 Aliases from nscfg.toml and included alias packs
+mkdir packs
+echo "include = [\"packs/desktop.toml\"]" >> nscfg.toml
+echo "[aliases]" >> nscfg.toml
+echo "local_desktop = \"linux:os | windows:os | macos:os\"" >> nscfg.toml
+echo "cargo_desktop = \"linux:os | windows:os | macos:os\"" >> nscfg.toml
+echo "include = [\"other.toml\"]" >> packs/desktop.toml
+echo "[aliases]" >> packs/desktop.toml
+echo "pack_desktop = \"linux:os | windows:os | macos:os\"" >> packs/desktop.toml
+echo "[aliases]" >> packs/other.toml
+echo "cargo_desktop = \"foo:os\"" >> packs/other.toml
+run_test 071.rs "Test 071 completed!"
+
+#T72 Same alias defined in 2 alias packs
+echo "pack_desktop = \"foo:os\"" >> packs/other.toml
+run_test 072.rs "alias \`pack_desktop\` is already defined in"
+rm -f nscfg.toml
+rm -rf packs
+
+#T73 Crate-scoped alias has priority over global alias
+echo "nscfg-scoped_desktop = \"foo:os\"" >> .cargo/config.toml
+echo "nscfg-global_desktop = \"linux:os | windows:os | macos:os\"" >> .cargo/config.toml
+echo "\"nscfg@nscfg_test-scoped_desktop\" = \"linux:os | windows:os | macos:os\"" >> .cargo/config.toml
+run_test 073.rs "Test 073 completed!"
+
+#T74 Strict mode ignores global aliases
+echo "nscfg_strict = \"true\"" >> .cargo/config.toml
+run_test 074.rs "cannot find macro \`nscfg_alias_global_desktop\`"
+sed -i '/nscfg_strict/d' .cargo/config.toml
+
+#T75 NSCFGError::AliasCycle
+echo "nscfg-cycle_a = \"b:_ | cycle_c\"" >> .cargo/config.toml
+echo "nscfg-cycle_c = \"cycle_a\"" >> .cargo/config.toml
+run_test 075.rs "Alias cycle detected : \`cycle_a -> cycle_c -> cycle_a\`."
+
+#T76 NSCFGError::AliasDepthExceeded
+echo "nscfg-chain_1 = \"chain_2\"" >> .cargo/config.toml
+echo "nscfg-chain_2 = \"chain_3\"" >> .cargo/config.toml
+echo "nscfg-chain_3 = \"linux:os\"" >> .cargo/config.toml
+echo "nscfg_max_alias_depth = \"2\"" >> .cargo/config.toml
+run_test 076.rs "exceeds maximum depth of 2!"
+sed -i '/nscfg_max_alias_depth/d' .cargo/config.toml
+
+#T77 Aliases defined in source with cfg_alias!
+run_test 077.rs "Test 077 completed!"
+
+#T78 NSCFGError::AliasAlreadyDefined
+run_test 078.rs "Alias \`desktop\` is already defined as built-in alias!"
+
+#T79 In-source alias used before its definition
+run_test 079.rs "consider moving the definition of \`nscfg_alias_late_desktop\` before this call"
+
+#T80 target_cfg! first option only includes first matching arm
+run_test 080.rs "Test 080 completed!"
+
+#T81 target_cfg! first option wildcard arm fallback
+run_test 081.rs "Test 081 completed!"
+
+#T82 NSCFGError::UnknownOption
+run_test 082.rs "Unknown target_cfg! option \`frist\`."
+
+#T83 NSCFGError::NonExhaustiveArms
+run_test 083.rs "No arm of exhaustive target_cfg! matches this configuration! Arms \`foo:os\`, \`windows:os | macos:os\` don't cover \`target_os = \"linux\"\`."
+
+#T84 Exhaustive target_cfg! with a matching arm
+run_test 084.rs "Test 084 completed!"
+
+#T85 NSCFGError::OverlappingArms
+run_test 085.rs "Arms \`unix\` and \`linux:os\` of disjoint target_cfg! both match this configuration!"
+
+#T86 Disjoint target_cfg! without overlapping arms
+run_test 086.rs "Test 086 completed!"
+
+#T87 match_cfg! without wildcard arm covering supported targets
+echo "nscfg_targets = \"x86_64-unknown-linux-gnu, x86_64-pc-windows-msvc, aarch64-apple-darwin\"" >> .cargo/config.toml
+run_test 087.rs "Test 087 completed!"
+
+#T88 NSCFGError::UncoveredTargets
+run_test 088.rs "Arms don't cover supported targets \`x86_64-pc-windows-msvc\`, \`aarch64-apple-darwin\`!"
+sed -i '/nscfg_targets/d' .cargo/config.toml
+
+#T89 Arms content with generics, closures and return types
+run_test 089.rs "Test 089 completed!"
+
+#T90 Items with initializers, macros, extern blocks and where clauses
+run_test 090.rs "Test 090 completed!"
+
+#T91 target_cfg! items using statement keywords and items inside a function
+run_test 091.rs "Test 091 completed!"
+
+#T92 stmt_cfg! statements with bindings visible to following code
+run_test 092.rs "Test 092 completed! 6"
+
+#T93 NSCFGError::WildcardArmOnTarget with stmt_cfg!
+run_test 093.rs "stmt_cfg! macro cannot have a \`_\` wildcard pattern."
+
+#T94 type_cfg! in fields, signatures, generics and type aliases
+run_test 094.rs "Test 094 completed! 2"
+
+#T95 NSCFGError::WildcardArmMissing with type_cfg!
+run_test 095.rs "Ensure that all possible cases are being handled by adding a match arm with a \`_\` wildcard pattern."
+
+#T96 cfg_consts! declaring the same consts for each platform
+run_test 096.rs "Test/096 completed! 4096"
+
+#T97 NSCFGError::ConstMissing and NSCFGError::ConstExpected
+run_test 097.rs "Arm \`macos\` doesn't define const \`MAX_FDS\`! Each arm of cfg_consts! must define the same consts."
+
+#T98 Raw cfg(...) and key = "value" leaves in simplified syntax
+run_test 098.rs "Test 098 completed!"
+
+#T99 Grouped values before a predicate like (linux | macos):os
+run_test 099.rs "Test 099 completed!"
+
+#T100 Range leaves like >=32:pw for pointer width and atomic widths
+run_test 100.rs "Test 100 completed!"
+
+#T101 NSCFGError::RangeOutOfDomain and NSCFGError::RangeNotSupported
+run_test 101.rs "Range \`>=48:pw\` is out of domain! Bound must be one of \`16\`, \`32\`, \`64\`"
+
+#T102 Leaves with hyphens, dots, numbers and quoted values
+echo "[build]" >> .cargo/config.toml
+echo "rustflags = [\"--cfg\", \"feature=\\\"my-feature\\\"\", \"--cfg\", \"feature=\\\"2d-render\\\"\", \"--cfg\", \"feature=\\\"v1.2\\\"\", \"--cfg\", \"feature=\\\"my feature\\\"\"]" >> .cargo/config.toml
+run_test 102.rs "Test 102 completed!"
+sed -i '/^\[build\]/d; /^rustflags/d' .cargo/config.toml
+
+#T103 NSCFGError::MissingOperator with leaf values separated by spaces
+run_test 103.rs "Operator \`&\` or '|' missing for"
+
+#T104 Parametrized aliases defined with cfg_alias! and in config.toml
+echo "\"nscfg-unix_arch(arch)\" = \"unix & {arch}:ar\"" >> .cargo/config.toml
+run_test 104.rs "Test 104 completed!"
+
+#T105 NSCFGError::AliasArityMismatch
+run_test 105.rs "Alias \`simd\` expects 2 argument(s), 1 given!"
+
+#T106 NSCFGError::AliasContentError
+echo "nscfg-broken_desktop = \"linux:os | (windows:os\"" >> .cargo/config.toml
+run_test 106.rs "Alias \`broken_desktop\` content \`linux:os | (windows:os\` can't be tokenized!"
+
+#T107 Many errors of match_cfg! in expression position are all reported
+run_test 107.rs "Configuration predicate \`arh\` not found, did you mean \`ar\` (built-in predicate)?"
+
+#T108 Environment variables that aren't unicode are ignored
+NSCFG_NOT_UNICODE=$'\xff' run_test 108.rs "Test 108 completed!"
+
+#T109 Alias lookup with environment variables that aren't unicode
+NSCFG_NOT_UNICODE=$'\xff' run_test 109.rs "cannot find macro \`nscfg_alias_linx\`"
+
+#T110 Crate is rebuilt when nscfg.toml changes
+echo "[aliases]" >> nscfg.toml
+echo "tracked_desktop = \"linux:os | windows:os | macos:os\"" >> nscfg.toml
+run_test 110.rs "Test 110 completed!"
+sed -i 's/^tracked_desktop = .*/tracked_desktop = "foo:os"/' nscfg.toml
+result="$(cargo run 2>&1)"
+if [[ "$result" == *"cannot find function \`foo\`"* ]]; then
+	test_passed 110.rs
+else
+	test_failed 110.rs "$result"
+fi
+rm -f nscfg.toml
+
+#T111 cfg_alias! with path of re-exported nscfg
+run_test 111.rs "Test 111 completed!"
+
+#T112 NSCFGError::NonExhaustiveArms with a deactivated arm
+run_test 112.rs "Arms \`linux:os\`, \`windows:os | macos:os\` don't cover \`target_os = \"linux\"\`."
+
+#T113 NSCFGError::OverlappingArms with an activated arm
+run_test 113.rs "Arms \`windows:os\` and \`linux:os\` of disjoint target_cfg! both match this configuration!"
+
+#T114 type_cfg! arm that changed meaning with operator precedence resolves to the type written
+run_test 114.rs "Test 114 completed!"
+
+#T115 Range leaves of atomic widths include ptr compared as pointer width. Panic message is compared without spaces since it's wrapped.
+cp -r "../tests/rs/115.rs" "src/main.rs"
+result="$(cargo run 2>&1 | tr -d ' \n')"
+if [[ "$result" == *'all(target_has_atomic="ptr",any(target_pointer_width="32",target_pointer_width="64"))'* ]]; then
+	test_passed 115.rs
+else
+	test_failed 115.rs "$result"
+fi
+
+#T116 NSCFGError::MissingOperator and NSCFGError::RangeOutOfDomain for spaced range and bound that isn't a number
+cp -r "../tests/rs/116.rs" "src/main.rs"
+result="$(cargo run 2>&1)"
+if [[ "$result" == *"missing for \`\">= 32 : pw\"\`"* && "$result" == *"Range \`>=ptr:at\` is out of domain! Bound must be one of \`8\`, \`16\`, \`32\`, \`64\`, \`128\`"* ]]; then
+	test_passed 116.rs
+else
+	test_failed 116.rs "$result"
+fi
+
+#T117 NSCFGError::MissingOperator for alias content with spaces inside values
+echo "nscfg-spaced_os = \"lin ux:os\"" >> .cargo/config.toml
+echo "nscfg-spaced_ft = \"my -feature:ft\"" >> .cargo/config.toml
+cp -r "../tests/rs/117.rs" "src/main.rs"
+result="$(cargo run 2>&1)"
+if [[ "$result" == *"missing for \`\"lin ux:os\"\`"* && "$result" == *"missing for \`\"my -feature:ft\"\`"* && "$result" == *"missing for \`\"lin ux : os\"\`"* ]]; then
+	test_passed 117.rs
+else
+	test_failed 117.rs "$result"
+fi
+
+#T118 Alias content with spaces between leaves and inside quoted values
+echo "nscfg-spaced_leaves = \"( linux:os | \\\"my feature\\\":ft ) & ! windows:os & target_pointer_width = \\\"64\\\":kv\"" >> .cargo/config.toml
+run_test 118.rs "Test 118 completed!"
+
+#T119 Values of nscfg.toml followed by a tab and a comment
+printf "autodoc = true\t# Comment\n[aliases]\ntabbed_desktop = \"linux:os | windows:os | macos:os\"\t# Comment\n" > nscfg.toml
+run_test 119.rs "Test 119 completed!"
+
+#T120 Built-in and env aliases and predicates resolved with an invalid nscfg.toml
+printf "[aliases\n" > nscfg.toml
+run_test 120.rs "Test 120 completed!"
+
+#T121 NSCFGError::ConfigurationError for an alias defined nowhere else
+run_test 121.rs "nscfg configuration error in"
+rm -f nscfg.toml
+
+#T122 stmt_cfg! block statements with struct patterns before their braces
+run_test 122.rs "Test 54 completed!"
+
+#T123 NSCFGError::UncoveredTargets with a deactivated arm
+echo "nscfg_targets = \"x86_64-unknown-linux-gnu, x86_64-pc-windows-msvc, aarch64-apple-darwin\"" >> .cargo/config.toml
+run_test 123.rs "Arms don't cover supported targets \`x86_64-unknown-linux-gnu\`!"
+sed -i '/nscfg_targets/d' .cargo/config.toml
+
 
 #########
 # TOTAL #
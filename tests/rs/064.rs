@@ -0,0 +1,33 @@
+// Test 064 : Operator precedence `!` > `&` > `|` and n-ary chains.
+use nscfg::{ target_cfg, match_cfg, meta_cfg };
+
+
+target_cfg! {
+	foo:os & foo:ar | debug => {	// any(all(foo, foo), debug)
+		pub fn foo1() -> String {
+			String::from("Test")
+		}
+	},
+	foo:os | foo:ar | foo:fm | debug => {	// any(foo, foo, foo, debug)
+		pub fn foo2() -> String {
+			String::from("064")
+		}
+	},
+}
+
+fn foo3() -> String {
+	match_cfg! {
+		!!foo:os => String::from("failed"),		// not(not(foo))
+		!foo:os & !foo:ar | foo:fm => String::from("completed"),	// any(all(not(foo), not(foo)), foo)
+		_ => String::from("failed"),
+	}
+}
+
+#[meta_cfg(debug | foo:os & foo:ar)]
+fn foo4() -> String {
+	String::from("!")
+}
+
+fn main() {
+    println!("{} {} {}{}", foo1(), foo2(), foo3(), foo4());
+}
@@ -0,0 +1,15 @@
+// Test 065 : Warning for arm that changed meaning with operator precedence.
+use nscfg::{ target_cfg };
+
+
+target_cfg! {
+	foo:os & foo:ar | debug => {
+		pub fn foo() -> String {
+			String::from("Test 065 completed!")
+		}
+	},
+}
+
+fn main() {
+    println!("{}", foo());
+}
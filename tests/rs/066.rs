@@ -0,0 +1,14 @@
+// Test 066 : Error located on the unknown alias instead of the whole macro.
+use nscfg::{ target_cfg };
+
+target_cfg!{
+    linux | desktap => {
+        pub fn foo() -> String {
+            String::from("Test 066 completed!")
+        }
+    },
+}
+
+fn main() {
+    println!("{}", foo());
+}
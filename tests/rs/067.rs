@@ -0,0 +1,19 @@
+// Test 067 : All errors reported in one expansion.
+use nscfg::{ target_cfg };
+
+target_cfg!{
+    linux:oss | windows:oz => {
+        pub fn foo() -> String {
+            String::from("Test 067 completed!")
+        }
+    },
+    android:oss => {
+        pub fn foo() -> String {
+            String::from("Test 067 completed!")
+        }
+    },
+}
+
+fn main() {
+    println!("{}", foo());
+}
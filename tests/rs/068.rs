@@ -0,0 +1,11 @@
+// Test 068 : Suggestion of nearest alias defined in env.
+use nscfg::{ meta_cfg };
+
+#[meta_cfg(frogg)]
+fn foo() -> String {
+    String::from("Test 068 completed!")
+}
+
+fn main() {
+    println!("{}", foo());
+}
@@ -0,0 +1,24 @@
+// Test 069 : Aliases and predicates defined in Cargo.toml [package.metadata.nscfg].
+use nscfg::{ target_cfg, meta_cfg };
+
+target_cfg! {
+    !cargo_desktop => {
+        fn bar() -> String {
+            String::from("Test 069 failed!")
+        }
+    },
+    cargo_desktop => {
+        fn bar() -> String {
+            String::from("Test 069 completed!")
+        }
+    },
+}
+
+#[meta_cfg(16:width | 32:width | 64:width)]
+fn foo() -> String {
+    bar()
+}
+
+fn main() {
+    println!("{}", foo());
+}
@@ -0,0 +1,19 @@
+// Test 070 : Aliases inherited from Cargo.toml [workspace.metadata.nscfg] and overridden by [package.metadata.nscfg].
+use nscfg::{ target_cfg };
+
+target_cfg! {
+    !(workspace_desktop & cargo_desktop) => {
+        fn foo() -> String {
+            String::from("Test 070 failed!")
+        }
+    },
+    workspace_desktop & cargo_desktop => {
+        fn foo() -> String {
+            String::from("Test 070 completed!")
+        }
+    },
+}
+
+fn main() {
+    println!("{}", foo());
+}
@@ -0,0 +1,19 @@
+// Test 071 : Aliases from nscfg.toml and included alias packs.
+use nscfg::{ target_cfg };
+
+target_cfg! {
+    !(local_desktop & pack_desktop & cargo_desktop) => {
+        fn foo() -> String {
+            String::from("Test 071 failed!")
+        }
+    },
+    local_desktop & pack_desktop & cargo_desktop => {
+        fn foo() -> String {
+            String::from("Test 071 completed!")
+        }
+    },
+}
+
+fn main() {
+    println!("{}", foo());
+}
@@ -0,0 +1,19 @@
+// Test 072 : Aliases from nscfg.toml : Same alias defined in 2 alias packs.
+use nscfg::{ target_cfg };
+
+target_cfg! {
+    !(local_desktop & pack_desktop & cargo_desktop) => {
+        fn foo() -> String {
+            String::from("Test 072 failed!")
+        }
+    },
+    local_desktop & pack_desktop & cargo_desktop => {
+        fn foo() -> String {
+            String::from("Test 072 completed!")
+        }
+    },
+}
+
+fn main() {
+    println!("{}", foo());
+}
@@ -0,0 +1,19 @@
+// Test 073 : Crate-scoped alias has priority over global alias.
+use nscfg::{ target_cfg };
+
+target_cfg! {
+    !scoped_desktop => {
+        fn foo() -> String {
+            String::from("Test 073 failed!")
+        }
+    },
+    scoped_desktop => {
+        fn foo() -> String {
+            String::from("Test 073 completed!")
+        }
+    },
+}
+
+fn main() {
+    println!("{}", foo());
+}
@@ -0,0 +1,11 @@
+// Test 074 : Strict mode ignores global aliases.
+use nscfg::{ meta_cfg };
+
+#[meta_cfg(global_desktop)]
+fn foo() -> String {
+    String::from("Test 074 completed!")
+}
+
+fn main() {
+    println!("{}", foo());
+}
@@ -0,0 +1,11 @@
+// Test 075 : NSCFGError::AliasCycle
+use nscfg::{ meta_cfg };
+
+#[meta_cfg(cycle_a)]
+fn foo() -> String {
+    String::from("Test 075 completed!")
+}
+
+fn main() {
+    println!("{}", foo());
+}
@@ -0,0 +1,11 @@
+// Test 076 : NSCFGError::AliasDepthExceeded
+use nscfg::{ meta_cfg };
+
+#[meta_cfg(chain_1)]
+fn foo() -> String {
+    String::from("Test 076 completed!")
+}
+
+fn main() {
+    println!("{}", foo());
+}
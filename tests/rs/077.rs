@@ -0,0 +1,32 @@
+// Test 077 : Aliases defined in source with cfg_alias!
+use nscfg::{ cfg_alias, target_cfg, match_cfg, meta_cfg };
+
+cfg_alias!{
+    source_desktop = linux:os | windows:os | macos:os;
+    source_not_desktop = !source_desktop;
+}
+
+target_cfg! {
+    source_not_desktop => {
+        fn bar() -> String {
+            String::from("Test 077 failed!")
+        }
+    },
+    source_desktop => {
+        fn bar() -> String {
+            String::from("Test 077 completed!")
+        }
+    },
+}
+
+#[meta_cfg(source_desktop & !source_not_desktop)]
+fn foo() -> String {
+    match_cfg! {
+        source_not_desktop => String::from("Test 077 failed!"),
+        _ => bar(),
+    }
+}
+
+fn main() {
+    println!("{}", foo());
+}
@@ -0,0 +1,10 @@
+// Test 078 : NSCFGError::AliasAlreadyDefined
+use nscfg::{ cfg_alias };
+
+cfg_alias!{
+    desktop = linux:os;
+}
+
+fn main() {
+    println!("Test 078 completed!");
+}
@@ -0,0 +1,15 @@
+// Test 079 : In-source alias used before its definition.
+use nscfg::{ cfg_alias, meta_cfg };
+
+#[meta_cfg(late_desktop)]
+fn foo() -> String {
+    String::from("Test 079 completed!")
+}
+
+cfg_alias!{
+    late_desktop = linux:os | windows:os | macos:os;
+}
+
+fn main() {
+    println!("{}", foo());
+}
@@ -0,0 +1,25 @@
+// Test 080 : target_cfg! with first option only includes the first matching arm
+use nscfg::{ target_cfg };
+
+target_cfg! {
+    #![first]
+    linux:os => {
+        fn foo() -> String {
+            String::from("Test 080 completed!")
+        }
+    },
+    unix => {
+        fn foo() -> String {
+            String::from("Test 080 failed!")
+        }
+    },
+    _ => {
+        fn foo() -> String {
+            String::from("Test 080 failed!")
+        }
+    },
+}
+
+fn main() {
+    println!("{}", foo());
+}
@@ -0,0 +1,25 @@
+// Test 081 : target_cfg! with first option falls back to wildcard arm
+use nscfg::{ target_cfg };
+
+target_cfg! {
+    #![first]
+    foo:os => {
+        fn foo() -> String {
+            String::from("Test 081 failed!")
+        }
+    },
+    !unix => {
+        fn foo() -> String {
+            String::from("Test 081 failed!")
+        }
+    },
+    _ => {
+        fn foo() -> String {
+            String::from("Test 081 completed!")
+        }
+    },
+}
+
+fn main() {
+    println!("{}", foo());
+}
@@ -0,0 +1,15 @@
+// Test 082 : NSCFGError::UnknownOption
+use nscfg::{ target_cfg };
+
+target_cfg! {
+    #![frist]
+    linux:os => {
+        fn foo() -> String {
+            String::from("Test 082 completed!")
+        }
+    },
+}
+
+fn main() {
+    println!("{}", foo());
+}
@@ -0,0 +1,20 @@
+// Test 083 : NSCFGError::NonExhaustiveArms
+use nscfg::{ target_cfg };
+
+target_cfg! {
+    #![exhaustive]
+    foo:os => {
+        fn foo() -> String {
+            String::from("Test 083 completed!")
+        }
+    },
+    windows:os | macos:os => {
+        fn foo() -> String {
+            String::from("Test 083 completed!")
+        }
+    },
+}
+
+fn main() {
+    println!("{}", foo());
+}
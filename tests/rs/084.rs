@@ -0,0 +1,35 @@
+// Test 084 : Exhaustive target_cfg! with an arm matching
+use nscfg::{ target_cfg };
+
+target_cfg! {
+    #![exhaustive]
+    windows:os => {
+        fn foo() -> String {
+            String::from("Test 084 failed!")
+        }
+    },
+    linux:os | macos:os => {
+        fn foo() -> String {
+            String::from("Test 084 completed!")
+        }
+    },
+}
+
+target_cfg! {
+    #![first, exhaustive]
+    foo:os => {
+        fn bar() -> String {
+            String::from("Test 084 failed!")
+        }
+    },
+    _ => {
+        fn bar() -> String {
+            String::from("Test 084 completed!")
+        }
+    },
+}
+
+fn main() {
+    assert_eq!(foo(), bar());
+    println!("{}", foo());
+}
@@ -0,0 +1,25 @@
+// Test 085 : NSCFGError::OverlappingArms
+use nscfg::{ target_cfg };
+
+target_cfg! {
+    #![disjoint]
+    unix => {
+        fn foo() -> String {
+            String::from("Test 085 completed!")
+        }
+    },
+    windows:os => {
+        fn foo() -> String {
+            String::from("Test 085 completed!")
+        }
+    },
+    linux:os => {
+        fn bar() -> String {
+            String::from("Test 085 completed!")
+        }
+    },
+}
+
+fn main() {
+    println!("{}", foo());
+}
@@ -0,0 +1,25 @@
+// Test 086 : Disjoint target_cfg! with no overlapping arms
+use nscfg::{ target_cfg };
+
+target_cfg! {
+    #![disjoint, exhaustive]
+    windows:os => {
+        fn foo() -> String {
+            String::from("Test 086 failed!")
+        }
+    },
+    linux:os => {
+        fn foo() -> String {
+            String::from("Test 086 completed!")
+        }
+    },
+    !windows:os & !linux:os => {
+        fn foo() -> String {
+            String::from("Test 086 failed!")
+        }
+    },
+}
+
+fn main() {
+    println!("{}", foo());
+}
@@ -0,0 +1,28 @@
+// Test 087 : match_cfg! without wildcard arm covering supported targets
+use nscfg::{ match_cfg, target_cfg };
+
+target_cfg! {
+    #![exhaustive]
+    unix => {
+        fn bar() -> String {
+            String::from("Test 087 completed!")
+        }
+    },
+    windows => {
+        fn bar() -> String {
+            String::from("Test 087 failed!")
+        }
+    },
+}
+
+fn foo() -> String {
+    match_cfg! {
+        linux => bar(),
+        #[cfg(target_os = "windows")] => String::from("Test 087 failed!"),
+        macos & (x86_64:ar | aarch64:ar) => String::from("Test 087 failed!"),
+    }
+}
+
+fn main() {
+    println!("{}", foo());
+}
@@ -0,0 +1,14 @@
+// Test 088 : NSCFGError::UncoveredTargets
+use nscfg::{ match_cfg };
+
+fn foo() -> String {
+    match_cfg! {
+        linux => String::from("Test 088 completed!"),
+        windows & foo:ft => String::from("Test 088 failed!"),
+        macos & x86_64:ar => String::from("Test 088 failed!"),
+    }
+}
+
+fn main() {
+    println!("{}", foo());
+}
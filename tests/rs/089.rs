@@ -0,0 +1,36 @@
+// Test 089 : Arms content with generics, closures and return types
+use std::collections::HashMap;
+use nscfg::{ match_cfg };
+
+fn foo() -> String {
+    let mut map = match_cfg! {
+        linux => HashMap::<String, u32>::new(),
+        _ => HashMap::<String, u32>::with_capacity(1),
+    };
+
+    let add = match_cfg! {
+        linux => |a : u32, b : u32| -> Result<u32, String> { Ok(a + b) },
+        _ => move |a : u32, b : u32| -> Result<u32, String> { Err(format!("{}{}", a, b)) },
+    };
+
+    let values = match_cfg! {
+        linux => <Vec<u32> as Default>::default(),
+        _ => Vec::<u32>::with_capacity(2),
+    };
+
+    let compare = match_cfg! {
+        linux => 1 < 2 && values.len() as u32 == 0 || false,
+        _ => 2 > 1,
+    };
+
+    map.insert(String::from("sum"), add(1, 2).unwrap());
+
+    match_cfg! {
+        linux => if compare && map["sum"] == 3 { String::from("Test 089 completed!") } else { String::from("Test 089 failed!") },
+        _ => String::from("Test 089 failed!"),
+    }
+}
+
+fn main() {
+    println!("{}", foo());
+}
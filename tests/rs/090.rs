@@ -0,0 +1,52 @@
+// Test 090 : Items with initializers, macros, extern blocks and where clauses
+use nscfg::{ target_cfg };
+
+pub struct Cfg {
+    a : u32,
+}
+
+pub struct Values<T>(Vec<T>);
+
+target_cfg! {
+    linux => {
+        static S : Cfg = Cfg { a : 1 };
+        const C : Cfg = Cfg { a : 2 };
+
+        macro_rules! make_fn {
+            ($name:ident, $value:expr) => { fn $name() -> u32 { $value } };
+        }
+        make_fn! { three, 3 }
+        make_fn!(four, 4);
+
+        extern "C" {
+            fn abs(input : i32) -> i32;
+        }
+
+        /// Documented impl
+        #[allow(dead_code)]
+        impl<T> Values<T> where T : Into<u32> + Copy {
+            fn value(&self) -> u32 {
+                self.0.iter().map(|v| (*v).into()).sum()
+            }
+        }
+
+        pub(crate) const fn five() -> u32 { 5 }
+
+        use std::collections::{ HashMap, HashSet };
+
+        fn foo() -> String {
+            let _ : (HashMap<u8, u8>, HashSet<u8>) = (HashMap::new(), HashSet::new());
+            let sum = S.a + C.a + three() + four() + five() + unsafe { abs(-6) } as u32 + Values(vec![7u32]).value();
+            if sum == 28 {
+                String::from("Test 090 completed!")
+            } else {
+                String::from("Test 090 failed!")
+            }
+        }
+    },
+    windows => compile_error!("Test 090 failed!"),
+}
+
+fn main() {
+    println!("{}", foo());
+}
@@ -0,0 +1,44 @@
+// Test 091 : target_cfg! with items using statement keywords and inside a function
+use nscfg::{ target_cfg };
+
+trait Value {
+    fn value(&self) -> u32;
+}
+
+target_cfg! {
+    linux => {
+        impl Value for u32 {
+            fn value(&self) -> u32 {
+                let mut sum = 0;
+                for i in 0..*self {
+                    if i % 2 == 0 {
+                        sum += i;
+                    }
+                }
+                sum
+            }
+        }
+    },
+}
+
+fn foo() -> String {
+    target_cfg! {
+        linux => {
+            struct Local;
+            impl Local {
+                fn name() -> String {
+                    match 5u32.value() {
+                        6 => String::from("Test 091 completed!"),
+                        _ => String::from("Test 091 failed!"),
+                    }
+                }
+            }
+        },
+    }
+
+    Local::name()
+}
+
+fn main() {
+    println!("{}", foo());
+}
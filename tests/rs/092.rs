@@ -0,0 +1,44 @@
+// Test 092 : stmt_cfg! statements with bindings visible to following code
+use nscfg::{ stmt_cfg };
+
+fn foo() -> String {
+    let mut count = 0;
+    let values = vec![1, 2, 3];
+
+    stmt_cfg! {
+        linux => {
+            let name = "Test";
+            count += 1;
+            if count > 0 {
+                count += 1;
+            } else if count > 100 {
+                count = 0;
+            } else {
+                count -= 1;
+            }
+            'outer: for value in values.iter() {
+                match value {
+                    2 => break 'outer,
+                    _ => count += 1,
+                }
+            }
+            fn double(value : u32) -> u32 {
+                value * 2
+            }
+            let sum = values.iter().map(|v| { v * 2 }).sum::<u32>();
+            loop { break }
+            { count }.to_string();
+            println!("{}", sum)
+        },
+        unix => count = double(count),
+        windows => let name = "Failed",
+        debug => let id = 92,
+        !debug => let id = 92,
+    }
+
+    format!("{} {:03} completed! {}", name, id, count)
+}
+
+fn main() {
+    println!("{}", foo());
+}
@@ -0,0 +1,11 @@
+// Test 093 : NSCFGError::WildcardArmOnTarget error with stmt_cfg!.
+use nscfg::{ stmt_cfg };
+
+fn main() {
+    stmt_cfg! {
+        linux => let name = "Test 093",
+        _ => let name = "Test 093",
+    }
+
+    println!("{} completed!", name);
+}
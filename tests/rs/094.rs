@@ -0,0 +1,22 @@
+// Test 094 : type_cfg! in fields, signatures, generics and type aliases
+use std::collections::HashMap;
+use nscfg::{ type_cfg };
+
+type Handle = type_cfg!{ windows => *mut u8, unix => i32, _ => () };
+
+struct File {
+    handle : Handle,
+    names : type_cfg!{ linux => HashMap<u32, &'static str>, _ => Vec<&'static str> },
+}
+
+fn size(values : Vec<type_cfg!{ #[cfg(target_pointer_width = "64")] => u64, _ => u32 }>) -> type_cfg!{ linux => usize, _ => u8 } {
+    values.len() as type_cfg!{ linux => usize, _ => u8 }
+}
+
+fn main() {
+    let mut file = File { handle : 94, names : HashMap::new() };
+    file.names.insert(1, "Test");
+    let values : Vec<type_cfg!{ unix => fn(u32) -> String, _ => () }> = vec![|v| format!("{:03}", v)];
+
+    println!("{} {} completed! {}", file.names[&1], values[0](file.handle as u32), size(vec![1, 2]));
+}
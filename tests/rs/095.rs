@@ -0,0 +1,8 @@
+// Test 095 : NSCFGError::WildcardArmMissing with type_cfg!.
+use nscfg::{ type_cfg };
+
+fn main() {
+    let value : type_cfg!{ linux => u32, windows => u64 } = 95;
+
+    println!("Test {:03} completed!", value);
+}
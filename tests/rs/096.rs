@@ -0,0 +1,35 @@
+// Test 096 : cfg_consts! declaring the same consts for each platform
+use nscfg::{ cfg_consts };
+
+cfg_consts! {
+    windows => {
+        pub const SEPARATOR : char = '\\';
+        /// Maximum path length
+        pub const MAX_PATH : usize = 260;
+    },
+    linux => {
+        pub const SEPARATOR : char = '/';
+        /// Maximum path length
+        pub const MAX_PATH : usize = 4096;
+    },
+    unix => {
+        pub const MAX_PATH : usize = 1024;
+        pub const SEPARATOR : char = '|';
+    },
+    _ => {
+        pub const SEPARATOR : char = '?';
+        pub const MAX_PATH : usize = 0;
+    }
+}
+
+fn main() {
+    fn page() -> u32 {
+        cfg_consts! {
+            linux => const PAGE : u32 = 96,
+            _ => const PAGE : u32 = 0,
+        }
+        PAGE
+    }
+
+    println!("Test{}{:03} completed! {}", SEPARATOR, page(), MAX_PATH);
+}
@@ -0,0 +1,21 @@
+// Test 097 : NSCFGError::ConstMissing and NSCFGError::ConstExpected.
+use nscfg::{ cfg_consts };
+
+cfg_consts! {
+    linux => {
+        pub const PAGE_SIZE : usize = 4096;
+        pub const MAX_FDS : usize = 1024;
+    },
+    macos => {
+        pub const PAGE_SIZE : usize = 16384;
+    },
+    _ => {
+        pub const PAGE_SIZE : usize = 4096;
+        pub const MAX_FDS : usize = 256;
+        pub fn completed() {}
+    }
+}
+
+fn main() {
+    println!("Test 097 completed! {} {}", PAGE_SIZE, MAX_FDS);
+}
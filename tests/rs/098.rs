@@ -0,0 +1,27 @@
+// Test 098 : Raw cfg(...) and key = "value" leaves in simplified syntax
+use nscfg::{ target_cfg, match_cfg, meta_cfg };
+
+target_cfg! {
+    linux & cfg(target_env = "gnu") & !tokio_unstable:_ => {
+        fn test() -> &'static str {
+            "Test"
+        }
+    },
+    !(linux & cfg(target_env = "gnu")) | tokio_unstable:_ => {
+        fn test() -> &'static str {
+            "Failed"
+        }
+    },
+}
+
+#[meta_cfg(target_os = "linux" & target_pointer_width="64":kv)]
+fn id() -> u32 {
+    match_cfg! {
+        cfg(any(target_os = "linux", target_os = "macos")) & !cfg(target_abi = "eabihf") => 98,
+        _ => 0,
+    }
+}
+
+fn main() {
+    println!("{} {:03} completed!", test(), id());
+}
@@ -0,0 +1,28 @@
+// Test 099 : Grouped values before a predicate like (linux | macos):os
+use nscfg::{ cfg_alias, target_cfg, match_cfg };
+
+cfg_alias!{
+    bsd_like = (freebsd | netbsd | openbsd | linux):os;
+}
+
+target_cfg! {
+    (linux | macos | freebsd):os & !(arm | mips):ar => {
+        fn test() -> &'static str {
+            "Test"
+        }
+    },
+    !(linux | macos | freebsd):os | (arm | mips):ar => {
+        fn test() -> &'static str {
+            "Failed"
+        }
+    },
+}
+
+fn main() {
+    let id = match_cfg! {
+        bsd_like & ((unix & !wasm) | windows):fm => 99,
+        _ => 0,
+    };
+
+    println!("{} {:03} completed!", test(), id);
+}
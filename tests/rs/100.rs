@@ -0,0 +1,24 @@
+// Test 100 : Range leaves like >=32:pw for pointer width and atomic widths
+use nscfg::{ target_cfg, match_cfg };
+
+target_cfg! {
+    >=32:pw & <128:at => {
+        fn test() -> &'static str {
+            "Test"
+        }
+    },
+    <32:pw | !(>8:at) => {
+        fn test() -> &'static str {
+            "Failed"
+        }
+    },
+}
+
+fn main() {
+    let id = match_cfg! {
+        >32:at & <=64:pw & (>16:pw) => 100,
+        _ => 0,
+    };
+
+    println!("{} {:03} completed!", test(), id);
+}
@@ -0,0 +1,19 @@
+// Test 101 : NSCFGError::RangeOutOfDomain and NSCFGError::RangeNotSupported
+use nscfg::{ target_cfg };
+
+target_cfg! {
+    >=48:pw => {
+        fn completed() {}
+    },
+    <16:pw => {
+        fn completed() {}
+    },
+    >=2:ft => {
+        fn completed() {}
+    },
+}
+
+fn main() {
+    completed();
+    println!("Test 101 completed!");
+}
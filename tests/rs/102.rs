@@ -0,0 +1,24 @@
+// Test 102 : Leaves with hyphens, dots, numbers and quoted values
+use nscfg::{ target_cfg, match_cfg };
+
+target_cfg! {
+    my-feature:ft & 2d-render:ft & v1.2:ft & "my feature":ft & !i686:ar => {
+        fn test() -> &'static str {
+            "Test"
+        }
+    },
+    !(my-feature:ft & 2d-render:ft & v1.2:ft & "my feature":ft) | i686:ar => {
+        fn test() -> &'static str {
+            "Failed"
+        }
+    },
+}
+
+fn main() {
+    let id = match_cfg! {
+        (my-feature | "my feature"):ft & !v1.2:pn => 102,
+        _ => 0,
+    };
+
+    println!("{} {:03} completed!", test(), id);
+}
@@ -0,0 +1,11 @@
+// Test 103 : NSCFGError::MissingOperator with leaf values separated by spaces
+use nscfg::{ match_cfg };
+
+fn main() {
+    let id = match_cfg! {
+        my -feature:ft => 103,
+        _ => 0,
+    };
+
+    println!("Test {:03} completed!", id);
+}
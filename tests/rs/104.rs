@@ -0,0 +1,29 @@
+// Test 104 : Parametrized aliases like simd(arch, feat)
+use nscfg::{ cfg_alias, target_cfg, match_cfg };
+
+cfg_alias!{
+    simd(arch, feat) = {arch}:ar & {feat}:tf;
+    desktop_simd(feat) = desktop & simd(x86_64, {feat});
+}
+
+target_cfg! {
+    simd(x86_64, sse2) | simd(aarch64, neon) => {
+        fn test() -> &'static str {
+            "Test"
+        }
+    },
+    !(simd(x86_64, sse2) | simd(aarch64, neon)) => {
+        fn test() -> &'static str {
+            "Failed"
+        }
+    },
+}
+
+fn main() {
+    let id = match_cfg! {
+        desktop_simd(sse2) & unix_arch(x86_64) => 104,
+        _ => 0,
+    };
+
+    println!("{} {} completed!", test(), id);
+}
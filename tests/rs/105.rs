@@ -0,0 +1,17 @@
+// Test 105 : NSCFGError::AliasArityMismatch
+use nscfg::{ cfg_alias, target_cfg };
+
+cfg_alias!{
+    simd(arch, feat) = {arch}:ar & {feat}:tf;
+}
+
+target_cfg! {
+    simd(x86_64) => {
+        fn completed() {}
+    },
+}
+
+fn main() {
+    completed();
+    println!("Test 105 completed!");
+}
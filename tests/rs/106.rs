@@ -0,0 +1,11 @@
+// Test 106 : NSCFGError::AliasContentError
+use nscfg::{ meta_cfg };
+
+#[meta_cfg(broken_desktop)]
+fn foo() -> String {
+    String::from("Test 106 completed!")
+}
+
+fn main() {
+    println!("{}", foo());
+}
@@ -0,0 +1,12 @@
+// Test 107 : Many errors reported by match_cfg! in expression position
+use nscfg::{ match_cfg };
+
+fn main() {
+    let a = match_cfg! {
+        linux:oss => 1,
+        android:arh => 2,
+        _ => 3,
+    };
+
+    println!("Test {} completed!", a);
+}
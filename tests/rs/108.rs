@@ -0,0 +1,11 @@
+// Test 108 : Environment variables that aren't unicode are ignored
+use nscfg::{ meta_cfg };
+
+#[meta_cfg(linux | windows | macos)]
+fn foo() -> String {
+    String::from("Test 108 completed!")
+}
+
+fn main() {
+    println!("{}", foo());
+}
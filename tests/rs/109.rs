@@ -0,0 +1,11 @@
+// Test 109 : Suggestion of alias with environment variables that aren't unicode
+use nscfg::{ meta_cfg };
+
+#[meta_cfg(linx)]
+fn foo() -> String {
+    String::from("Test 109 completed!")
+}
+
+fn main() {
+    println!("{}", foo());
+}
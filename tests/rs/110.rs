@@ -0,0 +1,11 @@
+// Test 110 : Crate is rebuilt when nscfg.toml changes
+use nscfg::{ meta_cfg };
+
+#[meta_cfg(tracked_desktop)]
+fn foo() -> String {
+    String::from("Test 110 completed!")
+}
+
+fn main() {
+    println!("{}", foo());
+}
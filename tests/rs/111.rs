@@ -0,0 +1,23 @@
+// Test 111 : cfg_alias! with path of re-exported nscfg
+use nscfg::{ cfg_alias };
+
+mod reexport {
+    pub use nscfg::*;
+}
+
+cfg_alias!{
+    #![crate = crate::reexport]
+    exported_desktop = linux:os | windows:os | macos:os;
+}
+
+reexport::target_cfg!{
+    exported_desktop => {
+        fn foo() -> String {
+            String::from("Test 111 completed!")
+        }
+    },
+}
+
+fn main() {
+    println!("{}", foo());
+}
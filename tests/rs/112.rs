@@ -0,0 +1,20 @@
+// Test 112 : NSCFGError::NonExhaustiveArms with a deactivated arm
+use nscfg::{ target_cfg };
+
+target_cfg! {
+    #![exhaustive]
+    -linux:os => {
+        fn foo() -> String {
+            String::from("Test 112 completed!")
+        }
+    },
+    windows:os | macos:os => {
+        fn foo() -> String {
+            String::from("Test 112 completed!")
+        }
+    },
+}
+
+fn main() {
+    println!("{}", foo());
+}
@@ -0,0 +1,20 @@
+// Test 113 : NSCFGError::OverlappingArms with an activated arm
+use nscfg::{ target_cfg };
+
+target_cfg! {
+    #![disjoint]
+    +windows:os => {
+        fn foo() -> String {
+            String::from("Test 113 completed!")
+        }
+    },
+    linux:os => {
+        fn bar() -> String {
+            String::from("Test 113 completed!")
+        }
+    },
+}
+
+fn main() {
+    println!("{}", foo());
+}
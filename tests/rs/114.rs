@@ -0,0 +1,9 @@
+// Test 114 : type_cfg! arm that changed meaning with operator precedence resolves to the type written
+use nscfg::{ type_cfg };
+
+type Message = type_cfg!{ foo:os & foo:ar | unix => &'static str, _ => &'static str };
+
+fn main() {
+    let message : Message = "Test 114 completed!";
+    println!("{}", message);
+}
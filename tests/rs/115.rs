@@ -0,0 +1,14 @@
+// Test 115 : Range leaves of atomic widths include `ptr` compared as pointer width
+use nscfg::{ target_cfg };
+
+target_cfg! {
+    @>=32:at => {
+        fn foo() -> String {
+            String::from("Test 115 completed!")
+        }
+    },
+}
+
+fn main() {
+    println!("{}", foo());
+}
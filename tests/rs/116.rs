@@ -0,0 +1,16 @@
+// Test 116 : NSCFGError::MissingOperator and NSCFGError::RangeOutOfDomain for spaced range and bound that isn't a number
+use nscfg::{ target_cfg };
+
+target_cfg! {
+    >= 32 : pw => {
+        fn completed() {}
+    },
+    >=ptr:at => {
+        fn completed() {}
+    },
+}
+
+fn main() {
+    completed();
+    println!("Test 116 completed!");
+}
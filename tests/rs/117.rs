@@ -0,0 +1,25 @@
+// Test 117 : NSCFGError::MissingOperator for alias content with spaces inside values
+use nscfg::{ meta_cfg, cfg_alias };
+
+cfg_alias!{
+    spaced_source = lin ux:os;
+}
+
+#[meta_cfg(spaced_os)]
+fn foo() -> String {
+    String::from("Test 117 completed!")
+}
+
+#[meta_cfg(spaced_ft | unix)]
+fn bar() -> String {
+    String::from("Test 117 completed!")
+}
+
+#[meta_cfg(spaced_source)]
+fn baz() -> String {
+    String::from("Test 117 completed!")
+}
+
+fn main() {
+    println!("{}{}{}", foo(), bar(), baz());
+}
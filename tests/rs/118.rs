@@ -0,0 +1,11 @@
+// Test 118 : Alias content with spaces between leaves and inside quoted values
+use nscfg::{ meta_cfg };
+
+#[meta_cfg(spaced_leaves)]
+fn foo() -> String {
+    String::from("Test 118 completed!")
+}
+
+fn main() {
+    println!("{}", foo());
+}
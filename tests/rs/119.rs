@@ -0,0 +1,11 @@
+// Test 119 : Values of nscfg.toml followed by a tab and a comment
+use nscfg::{ meta_cfg };
+
+#[meta_cfg(tabbed_desktop)]
+fn foo() -> String {
+    String::from("Test 119 completed!")
+}
+
+fn main() {
+    println!("{}", foo());
+}
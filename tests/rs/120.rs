@@ -0,0 +1,22 @@
+// Test 120 : Built-in and env aliases and predicates resolved with an invalid nscfg.toml
+use nscfg::{ target_cfg, match_cfg };
+
+target_cfg!{
+    desktop | global_desktop => {
+        fn foo() -> String {
+            String::from("Test 120 completed!")
+        }
+    },
+    !desktop & !global_desktop => {
+        fn foo() -> String {
+            String::from("Test 120 completed!")
+        }
+    },
+}
+
+fn main() {
+    match_cfg!{
+        64:pw | 32:pw => println!("{}", foo()),
+        _ => println!("{}", foo()),
+    }
+}
@@ -0,0 +1,11 @@
+// Test 121 : Configuration error of an invalid nscfg.toml for an alias defined nowhere else
+use nscfg::{ meta_cfg };
+
+#[meta_cfg(cargo_desktop)]
+fn foo() -> String {
+    String::from("Test 121 completed!")
+}
+
+fn main() {
+    println!("{}", foo());
+}
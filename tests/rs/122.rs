@@ -0,0 +1,37 @@
+// Test 122 : stmt_cfg! block statements with struct patterns before their braces
+use nscfg::{ stmt_cfg };
+
+struct Point {
+    x : u32,
+    y : u32,
+}
+
+fn foo() -> String {
+    let mut count = 0;
+    let point = Point { x : 1, y : 2 };
+    let mut points = vec![Point { x : 3, y : 4 }, Point { x : 5, y : 6 }];
+
+    stmt_cfg! {
+        linux => {
+            if let Point { x : 10..=19, y } = &point {
+                count += y;
+            } else if let Point { x : x @ 0..=9, y } = point {
+                count += x + y;
+            }
+            for Point { x, y } in points.iter() {
+                count += x * y;
+            }
+            while let Some(Point { x, .. }) = points.pop() {
+                count += x;
+            }
+            count += 1
+        },
+        !linux => count = 122,
+    }
+
+    format!("Test {} completed!", count)
+}
+
+fn main() {
+    println!("{}", foo());
+}
@@ -0,0 +1,14 @@
+// Test 123 : NSCFGError::UncoveredTargets with a deactivated arm
+use nscfg::{ match_cfg };
+
+fn foo() -> String {
+    match_cfg! {
+        -linux => String::from("Test 123 completed!"),
+        #[cfg(target_os = "windows")] => String::from("Test 123 completed!"),
+        macos => String::from("Test 123 completed!"),
+    }
+}
+
+fn main() {
+    println!("{}", foo());
+}
@@ -2,6 +2,11 @@ use std::time::Instant;
 
 use super::ALIASES;
 use super::{get_nscfg_predicate, PREDICATES};
+use super::{get_edit_distance, get_suggestion, DefinitionSource};
+use super::{parse_nscfg_config, read_nscfg_config, NSCFG_METADATA_TABLE};
+use super::{get_nscfg_alias, get_env_keys, find_nscfg_alias, set_source_aliases, find_nscfg_dependency_name};
+use crate::toml::parse_toml;
+use crate::errors::NSCFGError;
 
 /// Test all predefined aliases
 #[test]
@@ -59,8 +64,8 @@ fn stress_performance_aliases() {
         let alias = ALIASES[i & (ALIASES.len() - 1)];
 
         // Test each alias picked
-        match super::get_nscfg_alias(alias.0) {
-            Ok(result) => {
+        match super::get_nscfg_alias(alias.0, &[]) {
+            Ok((result, _)) => {
                 // If result != value, panic!
                 if result.ne(alias.1) {
                     panic!("parse_alias_from_label_tests::{} test error. Expected {}, got {}!", "test_parse_alias_from_label", alias.1, result);
@@ -165,14 +170,301 @@ fn stress_performance_predicates() {
 
 }
 
+/// Test Levenshtein distance between strings
+#[test]
+fn edit_distance() {
+    assert_eq!(get_edit_distance("linux", "linux"), 0);
+    assert_eq!(get_edit_distance("linx", "linux"), 1);
+    assert_eq!(get_edit_distance("desktap", "desktop"), 1);
+    assert_eq!(get_edit_distance("kitten", "sitting"), 3);
+    assert_eq!(get_edit_distance("", "ios"), 3);
+}
+
+/// Test suggestion from built-in aliases and predicates
+#[test]
+fn builtin_suggestions() {
+    assert_eq!(get_suggestion("linx", super::ENV_KEY_ALIAS, &[], &ALIASES, &[]), Some((String::from("linux"), DefinitionSource::BuiltIn)));
+    assert_eq!(get_suggestion("mobil", super::ENV_KEY_ALIAS, &[], &ALIASES, &[]), Some((String::from("mobile"), DefinitionSource::BuiltIn)));
+    assert_eq!(get_suggestion("oss", super::ENV_KEY_PREDICATE, &[], &PREDICATES, &[]), Some((String::from("os"), DefinitionSource::BuiltIn)));
+    assert_eq!(get_suggestion("not_even_close", super::ENV_KEY_ALIAS, &[], &ALIASES, &[]), None);
+}
+
+/// Test suggestion from aliases defined in env
+#[test]
+fn env_suggestions() {
+    // Key only used by this test since tests run in parallel
+    let key = format!("{}{}", super::ENV_KEY_ALIAS, "env_suggestions_alias");
+    std::env::set_var(&key, "foo:os");
+
+    let suggestion = get_suggestion("env_sugestions_alias", super::ENV_KEY_ALIAS, &[], &ALIASES, &[]);
+    std::env::remove_var(&key);
+
+    assert_eq!(suggestion, Some((String::from("env_suggestions_alias"), DefinitionSource::Env)));
+}
+
+/// Test suggestion from aliases defined in Cargo.toml
+#[test]
+fn config_suggestions() {
+    let config = vec![(String::from("my_linux"), String::from("linux:os"))];
+
+    assert_eq!(get_suggestion("my_linx", super::ENV_KEY_ALIAS, &config, &ALIASES, &[]), Some((String::from("my_linux"), DefinitionSource::Config)));
+    assert_eq!(get_suggestion("linx", super::ENV_KEY_ALIAS, &config, &ALIASES, &[]), Some((String::from("linux"), DefinitionSource::BuiltIn)));
+}
+
+/// Test reading [package.metadata.nscfg] from Cargo.toml
+#[test]
+fn metadata_config() {
+    let config = parse_package_config(r#"
+[package]
+name = "foo"
+version = "0.1.0"
+
+[package.metadata.nscfg]
+autodoc = false
+release_modifier_behaviour = "ignore"
+max_alias_depth = 8
+targets = ["x86_64-unknown-linux-gnu", "aarch64-apple-darwin"]
+
+[package.metadata.nscfg.aliases]
+server = "linux:os & x86_64:ar"
+"my-desktop" = 'windows:os | macos:os'
+
+[package.metadata.nscfg.predicates]
+pointer = "target_pointer_width = \"{}\""
+
+[dependencies]
+aliases = "1.0"
+"#).unwrap();
+
+    assert_eq!(config.aliases, vec![(String::from("server"), String::from("linux:os & x86_64:ar")), (String::from("my-desktop"), String::from("windows:os | macos:os"))]);
+    assert_eq!(config.predicates, vec![(String::from("pointer"), String::from("target_pointer_width = \"{}\""))]);
+    assert_eq!(config.autodoc, Some(false));
+    assert_eq!(config.release_modifier_behaviour, Some(String::from("ignore")));
+    assert_eq!(config.max_alias_depth, Some(8));
+    assert_eq!(config.targets, Some(vec![String::from("x86_64-unknown-linux-gnu"), String::from("aarch64-apple-darwin")]));
+
+    // Inline tables
+    let config = parse_package_config("[package.metadata.nscfg]\naliases = { server = \"linux:os\" }").unwrap();
+    assert_eq!(config.aliases, vec![(String::from("server"), String::from("linux:os"))]);
+
+    // No configuration
+    let config = parse_package_config("[package]\nname = \"foo\"").unwrap();
+    assert!(config.aliases.is_empty() && config.predicates.is_empty() && config.autodoc.is_none());
+}
+
+/// Test errors of [package.metadata.nscfg]
+#[test]
+fn metadata_config_errors() {
+    assert!(parse_package_config("[package.metadata.nscfg.aliases]\nserver = 1").is_err());
+    assert!(parse_package_config("[package.metadata.nscfg]\nautodoc = \"false\"").is_err());
+    assert!(parse_package_config("[package.metadata.nscfg]\naliases = \"linux:os\"").is_err());
+    assert!(parse_package_config("[package.metadata.nscfg]\nmax_alias_depth = -1").is_err());
+    assert!(parse_package_config("[package.metadata.nscfg]\ntargets = [\"x86_64-pc-windows-msvc\", 1]").is_err());
+    assert!(parse_package_config("[package.metadata.nscfg.aliases]\nserver = \"linux:os").is_err());
+}
+
+
+/// Test inheritance of [workspace.metadata.nscfg]
+#[test]
+fn workspace_config() {
+    // 1. Create workspace with a member overriding an alias and a member opting out
+    let root = std::env::temp_dir().join(format!("nscfg_workspace_{}", std::process::id()));
+    std::fs::create_dir_all(root.join("member")).unwrap();
+    std::fs::create_dir_all(root.join("crates").join("alone")).unwrap();
+
+    std::fs::write(root.join("Cargo.toml"), r#"
+[workspace]
+members = ["member", "crates/alone"]
+
+[workspace.metadata.nscfg]
+autodoc = false
+
+[workspace.metadata.nscfg.aliases]
+gpu = "nvptx64:ar | amdgpu:ar"
+console = "horizon:os"
+"#).unwrap();
+
+    let member = r#"
+[package]
+name = "member"
+
+[package.metadata.nscfg.aliases]
+console = "psp:os"
+"#;
+
+    let alone = r#"
+[package]
+name = "alone"
+
+[package.metadata.nscfg]
+inherit = false
+"#;
+
+    // 2. Member inherits workspace and overrides console
+    let config = read_nscfg_config(&root.join("member").join("Cargo.toml"), member).unwrap();
+    assert_eq!(config.aliases.iter().find(|a| a.0.eq("console")).unwrap().1, "psp:os");
+    assert_eq!(config.aliases.iter().find(|a| a.0.eq("gpu")).unwrap().1, "nvptx64:ar | amdgpu:ar");
+    assert_eq!(config.autodoc, Some(false));
+
+    // 3. Member opting out
+    let config = read_nscfg_config(&root.join("crates").join("alone").join("Cargo.toml"), alone).unwrap();
+    assert!(config.aliases.is_empty() && config.autodoc.is_none());
+
+    std::fs::remove_dir_all(root).unwrap();
+}
+
+/// Test manifests the TOML parser can't read while searching the workspace root
+#[test]
+fn workspace_unsupported_manifest() {
+    // 1. Workspace with an unrelated manifest between root and member
+    let root = std::env::temp_dir().join(format!("nscfg_workspace_unsupported_{}", std::process::id()));
+    let member_path = root.join("tools").join("member").join("Cargo.toml");
+    std::fs::create_dir_all(member_path.parent().unwrap()).unwrap();
+
+    std::fs::write(root.join("Cargo.toml"), "[workspace]\n\n[workspace.metadata.nscfg.aliases]\ngpu = \"nvptx64:ar\"\n").unwrap();
+    std::fs::write(root.join("tools").join("Cargo.toml"), "[package]\nname = \"tools\" + unsupported\n").unwrap();
+
+    let member = "[package]\nname = \"member\"\n";
+
+    // 2. Unrelated manifest is skipped
+    let config = read_nscfg_config(&member_path, member).unwrap();
+    assert_eq!(config.aliases.iter().find(|a| a.0.eq("gpu")).unwrap().1, "nvptx64:ar");
+
+    // 3. Workspace root without nscfg metadata stops the search without error
+    std::fs::write(root.join("tools").join("Cargo.toml"), "[workspace]\nmembers = [\"member\"] + unsupported\n").unwrap();
+    assert!(read_nscfg_config(&member_path, member).unwrap().aliases.is_empty());
+
+    // 4. Workspace root with nscfg metadata must be valid
+    std::fs::write(root.join("tools").join("Cargo.toml"), "[workspace]\nmembers = [\"member\"] + unsupported\n\n[workspace.metadata.nscfg]\nautodoc = false\n").unwrap();
+    assert!(read_nscfg_config(&member_path, member).err().unwrap().contains("tools"));
+
+    std::fs::remove_dir_all(root).unwrap();
+}
+
+/// Test name of nscfg renamed in dependencies
+#[test]
+fn renamed_dependency() {
+    assert_eq!(find_nscfg_dependency_name("[dependencies]\nnscfg = \"1.0\"\n"), None);
+    assert_eq!(find_nscfg_dependency_name("[dependencies]\ncfg-macros = { package = \"nscfg\", version = \"1.0\" }\n"), Some(String::from("cfg_macros")));
+    assert_eq!(find_nscfg_dependency_name("[target.'cfg(unix)'.build-dependencies.cfg]\npackage = \"nscfg\"\n"), Some(String::from("cfg")));
+}
+
+/// Test nscfg.toml and included alias packs
+#[test]
+fn local_file_and_packs() {
+    // 1. Create package with nscfg.toml including 2 packs
+    let root = std::env::temp_dir().join(format!("nscfg_packs_{}", std::process::id()));
+    std::fs::create_dir_all(root.join("packs")).unwrap();
+
+    std::fs::write(root.join("nscfg.toml"), r#"
+include = ["packs/gpu.toml", "packs/console.toml"]
+
+[aliases]
+gpu = "amdgpu:ar"
+"#).unwrap();
+    std::fs::write(root.join("packs").join("gpu.toml"), "include = [\"console.toml\"]\n[aliases]\ngpu = \"nvptx64:ar\"\ncuda = \"nvptx64:ar\"").unwrap();
+    std::fs::write(root.join("packs").join("console.toml"), "[aliases]\nconsole = \"horizon:os\"\n[predicates]\ncpu = 'target_cpu = \"{}\"'").unwrap();
+
+    let package = r#"
+[package]
+name = "packs"
+
+[package.metadata.nscfg.aliases]
+gpu = "spirv:ar"
+console = "psp:os"
+"#;
+
+    // 2. nscfg.toml > Cargo.toml > packs
+    let config = read_nscfg_config(&root.join("Cargo.toml"), package).unwrap();
+    assert_eq!(config.aliases.iter().find(|a| a.0.eq("gpu")).unwrap().1, "amdgpu:ar");
+    assert_eq!(config.aliases.iter().find(|a| a.0.eq("console")).unwrap().1, "psp:os");
+    assert_eq!(config.aliases.iter().find(|a| a.0.eq("cuda")).unwrap().1, "nvptx64:ar");
+    assert_eq!(config.predicates.iter().find(|p| p.0.eq("cpu")).unwrap().1, "target_cpu = \"{}\"");
+
+    // 2.1. Files read are tracked
+    let tracked : Vec<String> = config.files.iter().filter_map(|file| file.file_name()).map(|name| name.to_string_lossy().to_string()).collect();
+    assert_eq!(tracked, vec!["nscfg.toml", "gpu.toml", "console.toml"]);
+
+    // 3. Duplicate definition across packs
+    std::fs::write(root.join("packs").join("console.toml"), "[aliases]\ncuda = \"horizon:os\"").unwrap();
+    let err = read_nscfg_config(&root.join("Cargo.toml"), package).err().unwrap();
+    assert!(err.contains("gpu.toml") && err.contains("console.toml") && err.contains("alias `cuda`"));
+
+    // 4. Missing pack
+    std::fs::write(root.join("nscfg.toml"), "include = [\"packs/missing.toml\"]").unwrap();
+    assert!(read_nscfg_config(&root.join("Cargo.toml"), package).err().unwrap().contains("missing.toml"));
+
+    std::fs::remove_dir_all(root).unwrap();
+}
+
+/// Test crate-scoped keys
+#[test]
+fn crate_scoped_keys() {
+    // 1. Scoped key has priority over global key
+    std::env::set_var("nscfg-scoped_gpu", "windows:os");
+    std::env::set_var("nscfg@nscfg-scoped_gpu", "linux:os");
+    assert_eq!(get_nscfg_alias("scoped_gpu", &[]).ok(), Some((String::from("linux:os"), DefinitionSource::Env)));
+
+    // 2. Strict mode ignores global keys
+    assert_eq!(get_env_keys(super::ENV_KEY_ALIAS, false), vec![String::from("nscfg@nscfg-"), String::from("nscfg-")]);
+    assert_eq!(get_env_keys(super::ENV_KEY_PREDICATE, true), vec![String::from("nscfg_predicate@nscfg-")]);
+}
+
+/// Test aliases defined in source
+#[test]
+fn source_aliases() {
+    set_source_aliases(vec![(String::from("render_backend"), String::from("vulkan:ft | metal:ft"))]);
+
+    assert_eq!(find_nscfg_alias("render_backend").ok(), Some(Some((Vec::new(), String::from("vulkan:ft | metal:ft"), DefinitionSource::Source))));
+    assert_eq!(find_nscfg_alias("desktop").ok(), Some(Some((Vec::new(), String::from("linux:os | windows:_ | macos:os"), DefinitionSource::BuiltIn))));
+    assert_eq!(find_nscfg_alias("render_backends").ok(), Some(None));
+
+    set_source_aliases(Vec::new());
+}
+
+/// Test parametrized aliases
+#[test]
+fn parametrized_aliases() {
+    set_source_aliases(vec![(String::from("simd(arch, feat)"), String::from("{ arch } : ar & { feat } : tf & {other}:os"))]);
+
+    // 1. Found with name only
+    assert_eq!(find_nscfg_alias("simd").ok(), Some(Some((vec![String::from("arch"), String::from("feat")], String::from("{ arch } : ar & { feat } : tf & {other}:os"), DefinitionSource::Source))));
+
+    // 2. Arguments substituted, unknown placeholders kept
+    assert_eq!(get_nscfg_alias("simd", &[String::from("aarch64"), String::from("neon")]).ok(), Some((String::from("aarch64 : ar & neon : tf & {other}:os"), DefinitionSource::Source)));
+
+    // 3. Arity verified
+    assert!(matches!(get_nscfg_alias("simd", &[String::from("aarch64")]), Err(NSCFGError::AliasArityMismatch(_, 2, 1))));
+    assert!(matches!(get_nscfg_alias("desktop", &[String::from("linux")]), Err(NSCFGError::AliasArityMismatch(_, 0, 1))));
+
+    set_source_aliases(Vec::new());
+}
+
+/// Test parametrized aliases defined in env, read once for the macro being expanded
+#[test]
+fn env_parametrized_aliases() {
+    // Key only used by this test since tests run in parallel
+    let key = format!("{}{}", super::ENV_KEY_ALIAS, "env_simd(arch)");
+    std::env::set_var(&key, "{arch}:ar");
+
+    // 1. Found with name only
+    set_source_aliases(Vec::new());
+    assert_eq!(get_nscfg_alias("env_simd", &[String::from("aarch64")]).ok(), Some((String::from("aarch64:ar"), DefinitionSource::Env)));
+
+    // 2. Variables are read again by next expansion only
+    std::env::remove_var(&key);
+    assert!(get_nscfg_alias("env_simd", &[String::from("aarch64")]).is_ok());
+    set_source_aliases(Vec::new());
+    assert!(matches!(get_nscfg_alias("env_simd", &[String::from("aarch64")]), Err(NSCFGError::AliasNotFound(..))));
+}
 
 /************
 * FUNCTIONS * 
 ************/
 /// Test a pair of alias, alias value.
 fn test_parse_alias_from_label(alias : (&str, &str)) {
-    match super::get_nscfg_alias(alias.0) {
-        Ok(result) => {
+    match super::get_nscfg_alias(alias.0, &[]) {
+        Ok((result, _)) => {
             // If result != value, panic!
             if result.ne(alias.1) {
                 panic!("parse_alias_from_label_tests::{} test error. Expected {}, got {}!", "test_parse_alias_from_label", alias.1, result);
@@ -205,4 +497,9 @@ fn test_cfg_predicate(predicate_tested : (&str,&str)){
         // 4.2. Error occured, panic!
         Err(err) => panic!("{}", err.message(pred.as_str())),
     }
-}
\ No newline at end of file
+}
+
+/// Parse [package.metadata.nscfg] of Cargo.toml content.
+fn parse_package_config(content : &str) -> Result<super::NscfgConfig, String> {
+    parse_nscfg_config(&parse_toml(content)?, &NSCFG_METADATA_TABLE)
+}
@@ -0,0 +1,85 @@
+use std::rc::Rc;
+
+use super::TargetCfg;
+use crate::syntax::SyntaxTreeNode;
+
+/// Test configuration deduced from common triples
+#[test]
+fn triples() {
+    let linux = TargetCfg::from_triple("x86_64-unknown-linux-gnu");
+    assert_eq!(linux.evaluate("target_os", Some("linux")), Some(true));
+    assert_eq!(linux.evaluate("target_arch", Some("x86_64")), Some(true));
+    assert_eq!(linux.evaluate("target_env", Some("gnu")), Some(true));
+    assert_eq!(linux.evaluate("target_family", Some("unix")), Some(true));
+    assert_eq!(linux.evaluate("target_pointer_width", Some("64")), Some(true));
+    assert_eq!(linux.evaluate("unix", None), Some(true));
+    assert_eq!(linux.evaluate("windows", None), Some(false));
+
+    let macos = TargetCfg::from_triple("aarch64-apple-darwin");
+    assert_eq!(macos.evaluate("target_os", Some("macos")), Some(true));
+    assert_eq!(macos.evaluate("target_vendor", Some("apple")), Some(true));
+
+    let windows = TargetCfg::from_triple("i686-pc-windows-msvc");
+    assert_eq!(windows.evaluate("target_arch", Some("x86")), Some(true));
+    assert_eq!(windows.evaluate("target_env", Some("msvc")), Some(true));
+    assert_eq!(windows.evaluate("target_pointer_width", Some("32")), Some(true));
+    assert_eq!(windows.evaluate("windows", None), Some(true));
+
+    let android = TargetCfg::from_triple("armv7-linux-androideabi");
+    assert_eq!(android.evaluate("target_os", Some("android")), Some(true));
+    assert_eq!(android.evaluate("target_arch", Some("arm")), Some(true));
+    assert_eq!(android.evaluate("target_abi", Some("eabi")), Some(true));
+
+    let wasm = TargetCfg::from_triple("wasm32-unknown-unknown");
+    assert_eq!(wasm.evaluate("target_family", Some("wasm")), Some(true));
+    assert_eq!(wasm.evaluate("unix", None), Some(false));
+
+    let embedded = TargetCfg::from_triple("thumbv7em-none-eabihf");
+    assert_eq!(embedded.evaluate("target_os", Some("none")), Some(true));
+    assert_eq!(embedded.evaluate("target_abi", Some("eabihf")), Some(true));
+}
+
+/// Test that keys not decided by target are unknown
+#[test]
+fn undecided_keys() {
+    let linux = TargetCfg::from_triple("x86_64-unknown-linux-gnu");
+    assert_eq!(linux.evaluate("feature", Some("gpu")), None);
+    assert_eq!(linux.evaluate("debug_assertions", None), None);
+    assert_eq!(linux.evaluate("doc", None), Some(false));
+}
+
+/// Test syntax tree evaluation against a target
+#[test]
+fn evaluate_nodes() {
+    let linux = TargetCfg::from_triple("x86_64-unknown-linux-gnu");
+    let leaf = |predicate : &str| Rc::new(SyntaxTreeNode::LEAF(String::from(predicate), String::from(predicate)));
+
+    assert_eq!(leaf("target_os = \"linux\"").evaluate(&linux), Some(true));
+    assert_eq!(SyntaxTreeNode::not_node(leaf("windows")).evaluate(&linux), Some(true));
+    assert_eq!(SyntaxTreeNode::any_node(vec![leaf("feature = \"gpu\""), leaf("unix")]).evaluate(&linux), Some(true));
+    assert_eq!(SyntaxTreeNode::any_node(vec![leaf("feature = \"gpu\""), leaf("windows")]).evaluate(&linux), None);
+    assert_eq!(SyntaxTreeNode::all_node(vec![leaf("feature = \"gpu\""), leaf("windows")]).evaluate(&linux), Some(false));
+    assert_eq!(SyntaxTreeNode::all_node(vec![leaf("feature = \"gpu\""), leaf("unix")]).evaluate(&linux), None);
+    assert_eq!(SyntaxTreeNode::all_node(Vec::new()).evaluate(&linux), Some(true));
+    assert_eq!(SyntaxTreeNode::any_node(Vec::new()).evaluate(&linux), Some(false));
+}
+
+/// Test configurations split to name the one not covered by arms
+#[test]
+fn configurations() {
+    let leaves = |leaves : &[&str]| leaves.iter().map(|leaf| String::from(*leaf)).collect::<Vec<String>>();
+
+    // Known values are split while there are few configurations
+    let split = super::split_configurations(&leaves(&["target_os = \"windows\"", "unix"]), 256).unwrap();
+    assert_eq!(split.len(), 28 * 2);
+    assert!(split.contains(&(String::from("all(target_os = \"linux\", unix)"), String::from("target_os = \"linux\", unix"))));
+    assert!(split.contains(&(String::from("all(target_os = \"windows\", not(unix))"), String::from("target_os = \"windows\", not(unix)"))));
+
+    // Otherwise only values of leaves are split
+    let split = super::split_configurations(&leaves(&["target_os = \"windows\"", "target_arch = \"x86\""]), 64).unwrap();
+    assert_eq!(split.len(), 28 * 2);
+    assert!(split.contains(&(String::from("all(target_os = \"linux\", not(any(target_arch = \"x86\")))"), String::from("target_os = \"linux\", not(any(target_arch = \"x86\"))"))));
+
+    // Too many configurations
+    assert!(super::split_configurations(&leaves(&["a", "b", "c", "d"]), 8).is_none());
+}
@@ -0,0 +1,91 @@
+use super::{parse_toml, get_toml_table, get_toml_value, has_toml_table, TomlValue};
+
+/// Test tables, dotted and quoted keys
+#[test]
+fn tables_and_keys() {
+    let entries = parse_toml(r#"
+name = "root"
+a.b = "dotted"
+"quoted key".c = "quoted"
+
+[table]
+key = "value"   # Comment
+
+[[array]]
+key = "first"
+
+[ nested . "sub" ]
+key = 'literal'
+"#).unwrap();
+
+    assert_eq!(get_toml_value(&entries, &["name"]), Some(&TomlValue::String(String::from("root"))));
+    assert_eq!(get_toml_value(&entries, &["a", "b"]), Some(&TomlValue::String(String::from("dotted"))));
+    assert_eq!(get_toml_value(&entries, &["quoted key", "c"]), Some(&TomlValue::String(String::from("quoted"))));
+    assert_eq!(get_toml_value(&entries, &["table", "key"]), Some(&TomlValue::String(String::from("value"))));
+    assert_eq!(get_toml_value(&entries, &["array", "key"]), Some(&TomlValue::String(String::from("first"))));
+    assert_eq!(get_toml_value(&entries, &["nested", "sub", "key"]), Some(&TomlValue::String(String::from("literal"))));
+    assert_eq!(get_toml_table(&entries, &["nested"]).len(), 1);
+    assert_eq!(get_toml_value(&entries, &["missing"]), None);
+    assert_eq!(get_toml_value(&entries, &["table"]), Some(&TomlValue::Table(Vec::new())));
+}
+
+/// Test table existence
+#[test]
+fn table_existence() {
+    let entries = parse_toml("[workspace]\n[package]\nname = \"foo\"\nempty = {}\n[other.sub]\n").unwrap();
+
+    assert!(has_toml_table(&entries, &["workspace"]));
+    assert!(has_toml_table(&entries, &["package"]));
+    assert!(has_toml_table(&entries, &["package", "empty"]));
+    assert!(has_toml_table(&entries, &["other"]));
+    assert!(!has_toml_table(&entries, &["dependencies"]));
+    assert_eq!(get_toml_table(&entries, &["package"]).len(), 1);
+}
+
+/// Test strings, booleans, arrays, inline tables and other values
+#[test]
+fn values() {
+    let entries = parse_toml(r#"
+basic = "tab\t\"quote\" \u00e9"
+literal = 'C:\path'
+multi = """
+line 1
+line 2"""
+multi_literal = '''
+raw \n'''
+yes = true
+no = false
+tabbed = true	# Comment
+number = 42
+date = 1979-05-27 07:32:00
+array = [ "a",
+    'b', # Comment
+]
+inline = { key = "value", other.key = true }
+"#).unwrap();
+
+    assert_eq!(get_toml_value(&entries, &["basic"]), Some(&TomlValue::String(String::from("tab\t\"quote\" é"))));
+    assert_eq!(get_toml_value(&entries, &["literal"]), Some(&TomlValue::String(String::from("C:\\path"))));
+    assert_eq!(get_toml_value(&entries, &["multi"]), Some(&TomlValue::String(String::from("line 1\nline 2"))));
+    assert_eq!(get_toml_value(&entries, &["multi_literal"]), Some(&TomlValue::String(String::from("raw \\n"))));
+    assert_eq!(get_toml_value(&entries, &["yes"]), Some(&TomlValue::Boolean(true)));
+    assert_eq!(get_toml_value(&entries, &["no"]), Some(&TomlValue::Boolean(false)));
+    assert_eq!(get_toml_value(&entries, &["tabbed"]), Some(&TomlValue::Boolean(true)));
+    assert_eq!(get_toml_value(&entries, &["number"]), Some(&TomlValue::Other(String::from("42"))));
+    assert_eq!(get_toml_value(&entries, &["date"]), Some(&TomlValue::Other(String::from("1979-05-27 07:32:00"))));
+    assert_eq!(get_toml_value(&entries, &["array"]), Some(&TomlValue::Array(vec![TomlValue::String(String::from("a")), TomlValue::String(String::from("b"))])));
+    assert_eq!(get_toml_value(&entries, &["inline", "key"]), Some(&TomlValue::String(String::from("value"))));
+    assert_eq!(get_toml_value(&entries, &["inline", "other", "key"]), Some(&TomlValue::Boolean(true)));
+}
+
+/// Test invalid content
+#[test]
+fn errors() {
+    assert_eq!(parse_toml("key = \"unterminated"), Err(String::from("line 1 : unterminated string")));
+    assert_eq!(parse_toml("\n[table\nkey = 1"), Err(String::from("line 2 : `]` expected after table name")));
+    assert_eq!(parse_toml("key \"value\""), Err(String::from("line 1 : `=` expected after key")));
+    assert!(parse_toml("key = [1, 2").is_err());
+    assert!(parse_toml("key = { a = 1").is_err());
+    assert!(parse_toml("key = \"a\" b").is_err());
+    assert!(parse_toml("key = \"\\q\"").is_err());
+}